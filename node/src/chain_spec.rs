@@ -1,12 +1,12 @@
 use node_template_runtime::{
 	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY, NFTConfig, FTConfig
+	SystemConfig, WASM_BINARY, NFTConfig, FTConfig, FtPalletId, EXISTENTIAL_DEPOSIT
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{sr25519, Pair, Public};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Verify};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -139,7 +139,14 @@ fn testnet_genesis(
 		},
 		balances: BalancesConfig {
 			// Configure endowed accounts with initial balance of 1 << 60.
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
+			// The FT pallet account holds the existential deposit so that wrapped native currency
+			// is never reaped.
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, 1 << 60))
+				.chain(std::iter::once((FtPalletId::get().into_account_truncating(), EXISTENTIAL_DEPOSIT)))
+				.collect(),
 		},
 		aura: AuraConfig {
 			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
@@ -168,7 +175,23 @@ fn testnet_genesis(
 			]
 		},
 		ft: FTConfig {
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 40)).collect(),
+			assets: vec![
+				(
+					endowed_accounts[0].clone(),
+					b"Fungible Token".to_vec(),
+					b"FT".to_vec(),
+					12,
+					endowed_accounts.iter().cloned().map(|k| (k, 1 << 40)).collect(),
+				),
+				// Asset 1 is minted by wrapping native currency, see `NativeAssetId`.
+				(
+					endowed_accounts[0].clone(),
+					b"Wrapped Native".to_vec(),
+					b"WNAT".to_vec(),
+					12,
+					vec![],
+				),
+			]
 		}
 	}
 }
@@ -0,0 +1,123 @@
+//! RPC interface for the fungible token pallet.
+//!
+//! Every method takes an optional block hash and reads the best block when it is omitted.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_ft_runtime_api::FtApi as FtRuntimeApi;
+
+/// Metadata of an asset as returned by `ft_metadata`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AssetMetadata {
+	pub name: Bytes,
+	pub symbol: Bytes,
+	pub decimals: u8,
+}
+
+#[rpc(client, server)]
+pub trait FtApi<BlockHash, AccountId, AssetId, Balance> {
+	#[method(name = "ft_balanceOf")]
+	fn balance_of(&self, who: AccountId, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "ft_allowance")]
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "ft_totalSupply")]
+	fn total_supply(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "ft_metadata")]
+	fn metadata(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<AssetMetadata>>;
+}
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Provides the `ft_*` RPC methods.
+pub struct Ft<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Ft<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Unable to query pallet_ft.", Some(err.to_string()))).into()
+}
+
+impl<C, Block, AccountId, AssetId, Balance> FtApiServer<<Block as BlockT>::Hash, AccountId, AssetId, Balance>
+	for Ft<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: FtRuntimeApi<Block, AccountId, AssetId, Balance>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+	fn balance_of(
+		&self,
+		who: AccountId,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let balance = self.client.runtime_api().balance_of(&at, who, asset_id).map_err(runtime_error)?;
+		Ok(balance.into())
+	}
+
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let allowance =
+			self.client.runtime_api().allowance(&at, owner, spender, asset_id).map_err(runtime_error)?;
+		Ok(allowance.into())
+	}
+
+	fn total_supply(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let total_supply = self.client.runtime_api().total_supply(&at, asset_id).map_err(runtime_error)?;
+		Ok(total_supply.into())
+	}
+
+	fn metadata(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetMetadata>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let metadata = self.client.runtime_api().metadata(&at, asset_id).map_err(runtime_error)?;
+		Ok(metadata.map(|(name, symbol, decimals)| AssetMetadata {
+			name: name.into(),
+			symbol: symbol.into(),
+			decimals,
+		}))
+	}
+}
@@ -0,0 +1,26 @@
+//! Runtime API definition for the fungible token pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` and `unnecessary_mut_passed` warnings are triggered by the code
+// generated by `decl_runtime_apis!`.
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait FtApi<AccountId, AssetId, Balance> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the free balance of `who`.
+		fn balance_of(who: AccountId, asset_id: AssetId) -> Balance;
+		/// Returns the amount `spender` may still transfer from `owner`, zero once expired.
+		fn allowance(owner: AccountId, spender: AccountId, asset_id: AssetId) -> Balance;
+		/// Returns the total supply of an asset.
+		fn total_supply(asset_id: AssetId) -> Balance;
+		/// Returns `(name, symbol, decimals)` of an asset, or `None` if the asset does not exist.
+		fn metadata(asset_id: AssetId) -> Option<(Vec<u8>, Vec<u8>, u8)>;
+	}
+}
@@ -0,0 +1,149 @@
+//! Benchmarking setup for pallet-ft
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as FT;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Creates an asset endowed to `who` and sets up the most expensive transfer path: a transfer
+/// fee is charged and a snapshot is pending, so every touched balance is checkpointed.
+fn create_funded_asset<T: Config>(who: &T::AccountId) -> T::AssetId {
+	let asset_id = FT::<T>::do_create_asset(who).unwrap();
+	FT::<T>::do_mint(who, asset_id, T::Balance::from(1_000_000u32)).unwrap();
+	TransferFeeRate::<T>::insert(asset_id, 100);
+	FeeRecipient::<T>::insert(asset_id, account::<T::AccountId>("treasury", 0, SEED));
+	FT::<T>::do_snapshot(asset_id).unwrap();
+	asset_id
+}
+
+/// `n` transfers of 1_000 to distinct new accounts.
+fn batch<T: Config>(n: u32) -> BatchTransfersOf<T> {
+	(0..n)
+		.map(|i| (account::<T::AccountId>("to", i, SEED), T::Balance::from(1_000u32)))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+benchmarks! {
+	create_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = FT::<T>::next_asset_id();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(FT::<T>::assets(asset_id).map(|asset| asset.owner), Some(caller));
+	}
+
+	transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset_id = create_funded_asset::<T>(&caller);
+		let amount = T::Balance::from(1_000u32);
+	}: _(RawOrigin::Signed(caller.clone()), to.clone(), asset_id, amount)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &to), T::Balance::from(990u32));
+	}
+
+	approve {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let asset_id = create_funded_asset::<T>(&caller);
+		let amount = T::Balance::from(1_000u32);
+	}: _(RawOrigin::Signed(caller.clone()), spender.clone(), asset_id, amount, Some(T::BlockNumber::from(100u32)))
+	verify {
+		assert_eq!(FT::<T>::allowance_of(&caller, &spender, asset_id), amount);
+	}
+
+	transfer_from {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset_id = create_funded_asset::<T>(&owner);
+		let amount = T::Balance::from(1_000u32);
+		FT::<T>::approve(RawOrigin::Signed(owner.clone()).into(), caller.clone(), asset_id, amount, Some(T::BlockNumber::from(100u32)))?;
+	}: _(RawOrigin::Signed(caller), owner, to.clone(), asset_id, amount)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &to), T::Balance::from(990u32));
+	}
+
+	batch_transfer {
+		let n in 1 .. T::MaxBatchTransfers::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&caller);
+		let transfers = batch::<T>(n);
+	}: _(RawOrigin::Signed(caller), asset_id, transfers)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &account::<T::AccountId>("to", n - 1, SEED)), T::Balance::from(990u32));
+	}
+
+	batch_transfer_from {
+		let n in 1 .. T::MaxBatchTransfers::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&owner);
+		let transfers = batch::<T>(n);
+		let total = FT::<T>::batch_total(&transfers)?;
+		FT::<T>::approve(RawOrigin::Signed(owner.clone()).into(), caller.clone(), asset_id, total, Some(T::BlockNumber::from(100u32)))?;
+	}: _(RawOrigin::Signed(caller), owner, asset_id, transfers)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &account::<T::AccountId>("to", n - 1, SEED)), T::Balance::from(990u32));
+	}
+
+	revoke_all {
+		let n in 1 .. 1_000;
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&caller);
+		for i in 0..n {
+			let spender: T::AccountId = account("spender", i, SEED);
+			FT::<T>::approve(RawOrigin::Signed(caller.clone()).into(), spender, asset_id, T::Balance::from(1_000u32), None)?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, n, None)
+	verify {
+		assert!(FT::<T>::allowance_of(&caller, &account::<T::AccountId>("spender", n - 1, SEED), asset_id).is_zero());
+	}
+
+	mint {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset_id = create_funded_asset::<T>(&caller);
+		let amount = T::Balance::from(1_000u32);
+	}: _(RawOrigin::Signed(caller), to.clone(), asset_id, amount)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &to), amount);
+	}
+
+	burn {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&caller);
+		let amount = T::Balance::from(1_000u32);
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), asset_id, amount)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &caller), T::Balance::from(999_000u32));
+	}
+
+	burn_own {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&caller);
+		let amount = T::Balance::from(1_000u32);
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, amount)
+	verify {
+		assert_eq!(FT::<T>::balance(asset_id, &caller), T::Balance::from(999_000u32));
+	}
+
+	set_metadata {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = create_funded_asset::<T>(&caller);
+		let name = vec![b'x'; T::StringLimit::get() as usize];
+		let symbol = vec![b'X'; T::StringLimit::get() as usize];
+	}: _(RawOrigin::Signed(caller), asset_id, name.clone(), symbol.clone(), 12)
+	verify {
+		assert_eq!(FT::<T>::metadata_of(asset_id), Some((name, symbol, 12)));
+	}
+
+	impl_benchmark_test_suite!(FT, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
+}
@@ -0,0 +1,145 @@
+//! Implementations of the `fungibles` traits for the fungible token pallet.
+//!
+//! Consumers that expect a single-asset `fungible` implementation can use
+//! `frame_support::traits::tokens::fungible::ItemOf` to pin one asset of this pallet.
+
+use super::*;
+use frame_support::traits::tokens::{fungibles, DepositConsequence, WithdrawConsequence};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = T::AssetId;
+	type Balance = T::Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		TotalSupply::<T>::get(asset)
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		T::MinimumBalance::get()
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Balances::<T>::get(asset, who)
+	}
+
+	fn reducible_balance(asset: Self::AssetId, who: &T::AccountId, keep_alive: bool) -> Self::Balance {
+		if Pallet::<T>::ensure_can_move(Some(who), asset).is_err() {
+			return Zero::zero();
+		}
+		let locked = Pallet::<T>::locked_balance(who, asset);
+		let floor = if keep_alive { locked.max(T::MinimumBalance::get()) } else { locked };
+		Balances::<T>::get(asset, who).saturating_sub(floor)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		mint: bool,
+	) -> DepositConsequence {
+		if !Assets::<T>::contains_key(asset) {
+			return DepositConsequence::UnknownAsset;
+		}
+		if mint && TotalSupply::<T>::get(asset).checked_add(&amount).is_none() {
+			return DepositConsequence::Overflow;
+		}
+		let new_balance = match Balances::<T>::get(asset, who).checked_add(&amount) {
+			Some(new_balance) => new_balance,
+			None => return DepositConsequence::Overflow,
+		};
+		if new_balance < T::MinimumBalance::get()
+			&& !Balances::<T>::contains_key(asset, who)
+			&& Pallet::<T>::total_reserved_balance(who, asset).is_zero()
+		{
+			return DepositConsequence::BelowMinimum;
+		}
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if !Assets::<T>::contains_key(asset) {
+			return WithdrawConsequence::UnknownAsset;
+		}
+		if TotalSupply::<T>::get(asset).checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow;
+		}
+		let balance = Balances::<T>::get(asset, who);
+		if balance < amount {
+			return WithdrawConsequence::NoFunds;
+		}
+		if balance - amount < Pallet::<T>::locked_balance(who, asset)
+			|| Pallet::<T>::ensure_can_move(Some(who), asset).is_err()
+		{
+			return WithdrawConsequence::Frozen;
+		}
+		let remaining = balance - amount;
+		if !remaining.is_zero()
+			&& asset != T::NativeAssetId::get()
+			&& remaining < T::MinimumBalance::get()
+			&& Pallet::<T>::total_reserved_balance(who, asset).is_zero()
+		{
+			return WithdrawConsequence::ReducedToZero(remaining);
+		}
+		WithdrawConsequence::Success
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_not_native(asset)?;
+		Self::do_mint(who, asset, amount)
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_not_native(asset)?;
+		Self::do_burn(who, asset, amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> fungibles::Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let balance = Balances::<T>::get(asset, source);
+		if keep_alive && amount <= balance {
+			ensure!(balance - amount >= T::MinimumBalance::get(), Error::<T>::KeepAlive);
+		}
+		<Self as FTTransfer<T::AccountId, T::AssetId, T::Balance>>::transfer(source, dest, asset, amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
+	fn set_balance(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_asset_exists(asset)?;
+		Self::ensure_not_native(asset)?;
+		Self::update_account_snapshot(who, asset);
+		Self::write_balance(who, asset, amount);
+		if amount.is_zero() {
+			Self::reap_dust(who, asset);
+		}
+		Ok(())
+	}
+
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+		if asset == T::NativeAssetId::get() {
+			return;
+		}
+		Self::update_supply_snapshot(asset);
+		TotalSupply::<T>::insert(asset, amount);
+	}
+}
@@ -1,11 +1,109 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::pallet_prelude::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{tokens::BalanceStatus, Contains, Currency, ExistenceRequirement},
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
-use pallet_traits::{FTTransfer};
+use pallet_traits::{FTReserve, FTSnapshot, FTTransfer, OnTransfer, TransferFilter};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Convert, IdentifyAccount,
+		MaybeSerializeDeserialize, One, Saturating, Verify, Zero,
+	},
+	FixedPointOperand, FixedU128, RuntimeDebug,
+};
+use sp_std::vec::Vec;
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+mod impl_fungibles;
+pub mod migrations;
+pub mod payment;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Asset info
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct AssetInfo<AccountId> {
+	/// Asset owner
+	pub owner: AccountId,
+}
+
+/// Asset metadata
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct AssetMetadata<BoundedString> {
+	/// Asset name
+	pub name: BoundedString,
+	/// Asset symbol
+	pub symbol: BoundedString,
+	/// Number of decimals used to display balances
+	pub decimals: u8,
+}
+
+/// Allowance granted by an owner to a spender
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Approval<Balance, BlockNumber> {
+	/// Amount the spender may still transfer
+	pub amount: Balance,
+	/// Last block at which the allowance can be used, `None` if it never expires
+	pub expiry: Option<BlockNumber>,
+	/// Allowance epoch of the owner when the allowance was granted. Allowances from an earlier
+	/// epoch are void
+	pub epoch: u32,
+}
+
+/// Transfer fees are expressed in basis points of the transferred amount.
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// Identifier of a balance snapshot. Snapshot IDs start at 1 for every asset.
+pub type SnapshotId = u32;
+
+/// Vesting schedule of tokens received through a vested transfer
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct VestingInfo<Balance, BlockNumber> {
+	/// Tokens locked when the schedule was created
+	pub locked: Balance,
+	/// Tokens unlocked per block after `start`
+	pub per_block: Balance,
+	/// Block from which tokens start unlocking
+	pub start: BlockNumber,
+	/// Nothing unlocks before this block
+	pub cliff: BlockNumber,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned + Copy> VestingInfo<Balance, BlockNumber> {
+	/// Amount still locked at block `now`.
+	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(&self, now: BlockNumber) -> Balance {
+		if now < self.cliff {
+			return self.locked;
+		}
+		let vested_blocks = BlockNumberToBalance::convert(now.saturating_sub(self.start));
+		self.locked.saturating_sub(self.per_block.saturating_mul(vested_blocks))
+	}
+}
+
+/// An amount held under a named reserve or freeze
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct IdAmount<ReserveIdentifier, Balance> {
+	/// Identifier of the reserve or freeze
+	pub id: ReserveIdentifier,
+	/// Amount held under the identifier
+	pub amount: Balance,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -17,27 +115,214 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// The asset ID type
+		type AssetId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+		/// The balance type
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen + MaybeSerializeDeserialize + FixedPointOperand;
+		/// The origin which may mint and burn tokens of any account. The owner of an asset may
+		/// also mint and burn it
+		type MintOrigin: EnsureOrigin<Self::Origin>;
+		/// The origin which may change asset metadata. The owner of an asset may also change its
+		/// metadata
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+		/// The maximum length of an asset's name or symbol
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// The identifier of a named reserve or freeze
+		type ReserveIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+		/// The maximum number of named reserves an account can hold per asset
+		#[pallet::constant]
+		type MaxReserves: Get<u32>;
+		/// The maximum number of named freezes an account can hold per asset
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+		/// The signature type of off-chain `permit` approvals
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+		/// The public key type that signs off-chain `permit` approvals
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Converts a number of blocks into the amount unlocked by a vesting schedule
+		type BlockNumberToBalance: Convert<Self::BlockNumber, Self::Balance>;
+		/// The maximum number of vesting schedules an account can have per asset
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+		/// The minimum amount a vested transfer must lock
+		#[pallet::constant]
+		type MinVestedTransfer: Get<Self::Balance>;
+		/// Called after every balance move, including mints and burns
+		type OnTransfer: OnTransfer<Self::AccountId, Self::AssetId, Self::Balance>;
+		/// Can veto a balance move before it happens
+		type TransferFilter: TransferFilter<Self::AccountId, Self::AssetId, Self::Balance>;
+		/// Accounts that neither pay nor cause a transfer fee when sending or receiving
+		type FeeExempt: Contains<Self::AccountId>;
+		/// The native currency that can be wrapped into `NativeAssetId` 1:1
+		type Currency: Currency<Self::AccountId, Balance = Self::Balance>;
+		/// The asset minted when native currency is wrapped
+		#[pallet::constant]
+		type NativeAssetId: Get<Self::AssetId>;
+		/// Derives the account holding wrapped native currency
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The minimum free balance an account must hold. Accounts falling below it lose the
+		/// remainder and are removed
+		#[pallet::constant]
+		type MinimumBalance: Get<Self::Balance>;
+		/// The maximum number of snapshots at which a balance or total supply is remembered.
+		/// Older snapshots can no longer be queried once it is exceeded
+		#[pallet::constant]
+		type MaxSnapshotCheckpoints: Get<u32>;
+		/// The maximum number of recipients of a `batch_transfer` or `batch_transfer_from`
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
 	}
 
+	pub type AssetInfoOf<T> = AssetInfo<<T as frame_system::Config>::AccountId>;
+	pub type BoundedStringOf<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+	pub type AssetMetadataOf<T> = AssetMetadata<BoundedStringOf<T>>;
+	pub type ApprovalOf<T> = Approval<<T as Config>::Balance, <T as frame_system::Config>::BlockNumber>;
+	pub type VestingInfoOf<T> = VestingInfo<<T as Config>::Balance, <T as frame_system::Config>::BlockNumber>;
+	pub type BatchTransfersOf<T> = BoundedVec<(<T as frame_system::Config>::AccountId, <T as Config>::Balance), <T as Config>::MaxBatchTransfers>;
+	pub type IdAmountOf<T> = IdAmount<<T as Config>::ReserveIdentifier, <T as Config>::Balance>;
+	/// The last pruned snapshot ID and the `(snapshot_id, value)` checkpoints after it, sorted by
+	/// snapshot ID.
+	pub type CheckpointsOf<T> = (SnapshotId, BoundedVec<(SnapshotId, <T as Config>::Balance), <T as Config>::MaxSnapshotCheckpoints>);
+
+	pub type GenesisAssetData<T> = (
+		<T as frame_system::Config>::AccountId,             // Asset owner
+		Vec<u8>,                                            // Asset name
+		Vec<u8>,                                            // Asset symbol
+		u8,                                                 // Asset decimals
+		Vec<(<T as frame_system::Config>::AccountId, <T as Config>::Balance)>, // Initial balances of this asset
+	);
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// Next available asset ID.
+	#[pallet::storage]
+	#[pallet::getter(fn next_asset_id)]
+	pub type NextAssetId<T: Config> = StorageValue<_, T::AssetId, ValueQuery>;
+
+	/// Store asset info.
+	///
+	/// Returns `None` if the asset has not been created.
+	#[pallet::storage]
+	#[pallet::getter(fn assets)]
+	pub type Assets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, AssetInfoOf<T>>;
+
+	/// Store asset metadata.
+	#[pallet::storage]
+	#[pallet::getter(fn metadata)]
+	pub type Metadata<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, AssetMetadataOf<T>, ValueQuery>;
+
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/v3/runtime/storage
 	#[pallet::storage]
 	#[pallet::getter(fn balance)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/v3/runtime/storage#declaring-storage-items
-	pub type Balances<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u64, ValueQuery>;
+	pub type Balances<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// Number of accounts holding a balance of an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn holder_count)]
+	pub type HolderCount<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn total_supply)]
-	pub type TotalSupply<T> = StorageValue<_, u64, ValueQuery>;
+	pub type TotalSupply<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, T::Balance, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn allowance)]
-	pub type Allowances<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, u64, ValueQuery>;
+	pub type Allowances<T: Config> = StorageNMap<_, (
+		NMapKey<Twox64Concat, T::AssetId>,
+		NMapKey<Twox64Concat, T::AccountId>, // Owner
+		NMapKey<Twox64Concat, T::AccountId>, // Spender
+	), ApprovalOf<T>, ValueQuery>;
+
+	/// Allowance epoch of an owner, bumped when the owner is reaped to void every allowance it
+	/// granted without deleting them.
+	#[pallet::storage]
+	#[pallet::getter(fn allowance_epoch)]
+	pub type AllowanceEpochs<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Vesting schedules of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub type Vesting<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, BoundedVec<VestingInfoOf<T>, T::MaxVestingSchedules>, ValueQuery>;
+
+	/// Amount of `Balances` locked by vesting schedules, updated by `vested_transfer` and `vest`.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_lock)]
+	pub type VestingLocks<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// Units of an asset charged per unit of native transaction fee by `payment::FungibleTokenAdapter`.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_conversion_rate)]
+	pub type FeeConversionRate<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, FixedU128, OptionQuery>;
+
+	/// Fee charged on `transfer` and `transfer_from`, in basis points.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_fee_rate)]
+	pub type TransferFeeRate<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, u16, ValueQuery>;
+
+	/// Account receiving the transfer fees of an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_recipient)]
+	pub type FeeRecipient<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, T::AccountId, OptionQuery>;
+
+	/// Whether all assets are paused.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Assets paused individually.
+	#[pallet::storage]
+	pub type PausedAssets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, (), OptionQuery>;
+
+	/// Accounts that cannot send tokens of an asset.
+	#[pallet::storage]
+	pub type FrozenAccounts<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// ID of the latest snapshot taken of an asset, 0 if none was taken.
+	#[pallet::storage]
+	#[pallet::getter(fn current_snapshot_id)]
+	pub type CurrentSnapshotId<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, SnapshotId, ValueQuery>;
+
+	/// Balances of an account at past snapshots, checkpointed on the first balance change after
+	/// each snapshot.
+	#[pallet::storage]
+	pub type AccountCheckpoints<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, CheckpointsOf<T>, ValueQuery>;
+
+	/// Total supplies of an asset at past snapshots, checkpointed on the first supply change
+	/// after each snapshot.
+	#[pallet::storage]
+	pub type SupplyCheckpoints<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, CheckpointsOf<T>, ValueQuery>;
+
+	/// Next nonce accepted in a `permit` signed by an owner.
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	pub type PermitNonces<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Named reserves of an account.
+	///
+	/// Reserved tokens are moved out of `Balances` but still count towards `TotalSupply`.
+	#[pallet::storage]
+	#[pallet::getter(fn reserves)]
+	pub type Reserves<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, BoundedVec<IdAmountOf<T>, T::MaxReserves>, ValueQuery>;
+
+	/// Named freezes of an account.
+	///
+	/// Frozen tokens stay in `Balances`, but the balance cannot drop below the largest freeze.
+	#[pallet::storage]
+	#[pallet::getter(fn freezes)]
+	pub type Freezes<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AssetId, Twox64Concat, T::AccountId, BoundedVec<IdAmountOf<T>, T::MaxFreezes>, ValueQuery>;
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -45,8 +330,61 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
-		Transferred(T::AccountId, T::AccountId, u64),
-		Approved(T::AccountId, T::AccountId, u64),
+		AssetCreated(T::AssetId, T::AccountId),
+		Transferred(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		Approved(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// A balance snapshot was taken. [asset_id, snapshot_id]
+		SnapshotTaken(T::AssetId, SnapshotId),
+		/// A vesting schedule was created. [asset_id, from, to, locked]
+		VestingCreated(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// Unlocked vested tokens were released. [asset_id, who, still_locked]
+		Vested(T::AssetId, T::AccountId, T::Balance),
+		/// All allowances granted by an owner were revoked. [asset_id, owner]
+		AllApprovalsRevoked(T::AssetId, T::AccountId),
+		/// Some allowances granted by an owner were revoked. Pass `cursor` to `revoke_all` to
+		/// revoke the rest. [asset_id, owner, cursor]
+		ApprovalsPartiallyRevoked(T::AssetId, T::AccountId, Vec<u8>),
+		/// Tokens were minted. [asset_id, who, amount, total_supply]
+		Minted(T::AssetId, T::AccountId, T::Balance, T::Balance),
+		/// Tokens were burned. [asset_id, who, amount, total_supply]
+		Burned(T::AssetId, T::AccountId, T::Balance, T::Balance),
+		/// Asset metadata was set. [asset_id, name, symbol, decimals]
+		MetadataSet(T::AssetId, Vec<u8>, Vec<u8>, u8),
+		/// Tokens were moved into a named reserve. [asset_id, who, amount]
+		Reserved(T::AssetId, T::AccountId, T::Balance),
+		/// Tokens were moved out of a named reserve. [asset_id, who, amount]
+		Unreserved(T::AssetId, T::AccountId, T::Balance),
+		/// Reserved tokens were moved to another account. [asset_id, from, to, amount, destination_status]
+		ReserveRepatriated(T::AssetId, T::AccountId, T::AccountId, T::Balance, BalanceStatus),
+		/// A named freeze was set. [asset_id, who, amount]
+		FreezeSet(T::AssetId, T::AccountId, T::Balance),
+		/// A named freeze was removed. [asset_id, who]
+		Thawed(T::AssetId, T::AccountId),
+		/// Token movements were paused, for all assets if `None`. [asset_id]
+		Paused(Option<T::AssetId>),
+		/// Token movements were unpaused, for all assets if `None`. [asset_id]
+		Unpaused(Option<T::AssetId>),
+		/// An account can no longer send tokens of an asset. [asset_id, who]
+		AccountFrozen(T::AssetId, T::AccountId),
+		/// A frozen account can send tokens of an asset again. [asset_id, who]
+		AccountThawed(T::AssetId, T::AccountId),
+		/// The transfer fee of an asset was set. [asset_id, fee_bps, recipient]
+		TransferFeeSet(T::AssetId, u16, T::AccountId),
+		/// A transfer paid a fee. [asset_id, from, to, gross, net, fee]
+		FeeCharged(T::AssetId, T::AccountId, T::AccountId, T::Balance, T::Balance, T::Balance),
+		/// An account fell below the minimum balance and was removed, burning the remainder.
+		/// [asset_id, who, dust]
+		DustLost(T::AssetId, T::AccountId, T::Balance),
+		/// A batch of transfers completed. [asset_id, from, recipients, total]
+		BatchTransferred(T::AssetId, T::AccountId, u32, T::Balance),
+		/// Native currency was wrapped into `NativeAssetId`. [who, amount]
+		Wrapped(T::AccountId, T::Balance),
+		/// `NativeAssetId` was unwrapped into native currency. [who, amount]
+		Unwrapped(T::AccountId, T::Balance),
+		/// The transaction fee conversion rate of an asset was set, or removed if `None`. [asset_id, rate]
+		FeeConversionRateSet(T::AssetId, Option<FixedU128>),
+		/// A transaction fee was paid in an asset. [asset_id, who, actual_fee, refund]
+		TransactionFeePaid(T::AssetId, T::AccountId, T::Balance, T::Balance),
 	}
 
 	// Errors inform users that something went wrong.
@@ -57,19 +395,63 @@ pub mod pallet {
 
 		InsufficientBalance,
 
-		InsufficientAllowance
+		InsufficientAllowance,
+		/// No available asset ID
+		NoAvailableAssetId,
+		/// Asset not found
+		AssetNotFound,
+		/// Name or symbol is longer than `StringLimit`
+		BadMetadata,
+		/// The balance would drop below the frozen amount
+		LiquidityRestrictions,
+		/// The named reserve holds less than the requested amount
+		InsufficientReserve,
+		/// The account already holds `MaxReserves` named reserves
+		TooManyReserves,
+		/// The account already holds `MaxFreezes` named freezes
+		TooManyFreezes,
+		/// The permit deadline has passed
+		PermitExpired,
+		/// The permit nonce does not match the owner's next nonce
+		InvalidPermitNonce,
+		/// The permit was not signed by the owner
+		InvalidPermitSignature,
+		/// The allowance has expired
+		ApprovalExpired,
+		/// A vesting schedule must lock and unlock a non-zero amount
+		InvalidVestingSchedule,
+		/// The account already has `MaxVestingSchedules` vesting schedules
+		TooManyVestingSchedules,
+		/// A vested transfer must lock at least `MinVestedTransfer`
+		AmountLow,
+		/// The account has no vesting schedule
+		NotVesting,
+		/// Token movements are paused for this asset
+		AssetPaused,
+		/// The account is frozen for this asset
+		AccountIsFrozen,
+		/// The fee rate is above `MAX_FEE_BPS`
+		InvalidFeeRate,
+		/// The deposit would create an account holding less than the minimum balance
+		BelowMinimumBalance,
+		/// The origin is neither the privileged origin nor the asset owner
+		NoPermission,
+		/// A keep-alive transfer would drop the source below the minimum balance
+		KeepAlive,
+		/// `NativeAssetId` can only be minted and burned by `wrap` and `unwrap`
+		NativeAssetRestricted,
 	}
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
-		pub balances: Vec<(T::AccountId, u64)>,
+		pub assets: Vec<GenesisAssetData<T>>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			GenesisConfig {
-				balances: vec![]
+				assets: vec![]
 			}
 		}
 	}
@@ -77,127 +459,1163 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			self.balances.iter().for_each(|balance| {
-				Pallet::<T>::mint(&balance.0, balance.1).expect("Mint cannot fail during genesis");
+			self.assets.iter().for_each(|asset| {
+				let asset_id = Pallet::<T>::do_create_asset(&asset.0)
+					.expect("Create asset cannot fail while building genesis");
+				Pallet::<T>::do_set_metadata(asset_id, asset.1.to_vec(), asset.2.to_vec(), asset.3)
+					.expect("Set metadata cannot fail while building genesis");
+				asset.4.iter().for_each(|balance| {
+					Pallet::<T>::do_mint(&balance.0, asset_id, balance.1).expect("Mint cannot fail during genesis");
+				});
 			});
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+	}
+
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
-		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, amount: u64) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::create_asset())]
+		pub fn create_asset(origin: OriginFor<T>) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_create_asset(&creator)?;
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
 			let from = ensure_signed(origin)?;
-			Self::sub_balance(&from, amount)?;
-			Self::add_balance(&to, amount)?;
+			Self::do_transfer_with_fee(&from, &to, asset_id, amount)?;
 
-			Self::deposit_event(Event::Transferred(from.clone(), to.clone(), amount));
+			Ok(())
+		}
 
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+			expiry: Option<T::BlockNumber>
+		) -> DispatchResult{
+			let owner = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			let epoch = Self::allowance_epoch(asset_id, &owner);
+			Allowances::<T>::insert((asset_id, owner.clone(), spender.clone()), Approval { amount, expiry, epoch });
+			Self::deposit_event(Event::Approved(asset_id, owner.clone(), spender.clone(), amount));
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn approve(origin: OriginFor<T>, spender: T::AccountId, amount: u64) -> DispatchResult{
+		/// Raises an unexpired allowance by `delta`, keeping its expiry.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		pub fn increase_allowance(origin: OriginFor<T>, spender: T::AccountId, asset_id: T::AssetId, delta: T::Balance) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
-			Allowances::<T>::insert(owner.clone(), spender.clone(), amount);
-			Self::deposit_event(Event::Approved(owner.clone(), spender.clone(), amount));
+			Self::ensure_asset_exists(asset_id)?;
+			let epoch = Self::allowance_epoch(asset_id, &owner);
+			let amount = Allowances::<T>::try_mutate((asset_id, owner.clone(), spender.clone()), |approval| -> Result<T::Balance, DispatchError>{
+				Self::refresh_approval(approval, epoch);
+				ensure!(!Self::is_expired(approval), Error::<T>::ApprovalExpired);
+				approval.amount = approval.amount.checked_add(&delta).ok_or(Error::<T>::Overflow)?;
+				Ok(approval.amount)
+			})?;
+			Self::deposit_event(Event::Approved(asset_id, owner, spender, amount));
+			Ok(())
+		}
+
+		/// Lowers an allowance by `delta`, keeping its expiry.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		pub fn decrease_allowance(origin: OriginFor<T>, spender: T::AccountId, asset_id: T::AssetId, delta: T::Balance) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			let epoch = Self::allowance_epoch(asset_id, &owner);
+			let amount = Allowances::<T>::try_mutate((asset_id, owner.clone(), spender.clone()), |approval| -> Result<T::Balance, DispatchError>{
+				Self::refresh_approval(approval, epoch);
+				approval.amount = approval.amount.checked_sub(&delta).ok_or(Error::<T>::InsufficientAllowance)?;
+				Ok(approval.amount)
+			})?;
+			Self::deposit_event(Event::Approved(asset_id, owner, spender, amount));
+			Ok(())
+		}
+
+		/// Transfers `schedule.locked` tokens to `target` and locks them under the schedule.
+		#[pallet::weight(50_000 + T::DbWeight::get().reads_writes(4, 4))]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			target: T::AccountId,
+			asset_id: T::AssetId,
+			schedule: VestingInfoOf<T>
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			ensure!(!schedule.locked.is_zero() && !schedule.per_block.is_zero(), Error::<T>::InvalidVestingSchedule);
+			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+			Self::move_balance(&from, &target, asset_id, schedule.locked)?;
+			Vesting::<T>::try_mutate(asset_id, &target, |schedules| -> DispatchResult{
+				schedules.try_push(schedule.clone()).map_err(|_err| Error::<T>::TooManyVestingSchedules)?;
+				Ok(())
+			})?;
+			Self::update_vesting_lock(&target, asset_id);
+			Self::deposit_event(Event::Transferred(asset_id, from.clone(), target.clone(), schedule.locked));
+			Self::deposit_event(Event::VestingCreated(asset_id, from.clone(), target, schedule.locked));
+			Self::reap_dust(&from, asset_id);
+			Ok(())
+		}
+
+		/// Releases the caller's tokens that have unlocked so far.
+		#[pallet::weight(50_000 + T::DbWeight::get().reads_writes(2, 2))]
+		pub fn vest(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Vesting::<T>::contains_key(asset_id, &who), Error::<T>::NotVesting);
+			let still_locked = Self::update_vesting_lock(&who, asset_id);
+			Self::deposit_event(Event::Vested(asset_id, who, still_locked));
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(3))]
-		pub fn transfer_from(origin: OriginFor<T>, from: T::AccountId, to: T::AccountId, amount: u64) -> DispatchResult{
+		/// Removes up to `limit` allowances the caller has granted for an asset. Until
+		/// `AllApprovalsRevoked` is emitted, call it again with the cursor of the last
+		/// `ApprovalsPartiallyRevoked` event to remove the rest.
+		#[pallet::weight(T::WeightInfo::revoke_all(*limit))]
+		pub fn revoke_all(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			limit: u32,
+			cursor: Option<Vec<u8>>
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			let removal = Allowances::<T>::clear_prefix((asset_id, owner.clone()), limit, cursor.as_deref());
+			match removal.maybe_cursor {
+				Some(cursor) => Self::deposit_event(Event::ApprovalsPartiallyRevoked(asset_id, owner, cursor)),
+				None => Self::deposit_event(Event::AllApprovalsRevoked(asset_id, owner)),
+			}
+			Ok(Some(T::WeightInfo::revoke_all(removal.loops)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(origin: OriginFor<T>, from: T::AccountId, to: T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult{
 			let signer = ensure_signed(origin)?;
-			Self::sub_allowance(&from, &signer, amount)?;
-			Self::sub_balance(&from, amount)?;
-			Self::add_balance(&to, amount)?;
-			Self::deposit_event(Event::Transferred(from.clone(), to.clone(), amount));
+			Self::ensure_asset_exists(asset_id)?;
+			Self::sub_allowance(&from, &signer, asset_id, amount)?;
+			Self::do_transfer_with_fee(&from, &to, asset_id, amount)?;
+			Ok(())
+		}
+
+		/// Transfers to every recipient of `transfers`. Either all transfers succeed or none do.
+		#[pallet::weight(T::WeightInfo::batch_transfer(transfers.len() as u32))]
+		#[transactional]
+		pub fn batch_transfer(origin: OriginFor<T>, asset_id: T::AssetId, transfers: BatchTransfersOf<T>) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			Self::do_batch_transfer(&from, asset_id, transfers)
+		}
+
+		/// Like `batch_transfer`, spending the caller's allowance from `from` for the total amount.
+		#[pallet::weight(T::WeightInfo::batch_transfer_from(transfers.len() as u32))]
+		#[transactional]
+		pub fn batch_transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			asset_id: T::AssetId,
+			transfers: BatchTransfersOf<T>
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			let total = Self::batch_total(&transfers)?;
+			Self::sub_allowance(&from, &signer, asset_id, total)?;
+			Self::do_batch_transfer(&from, asset_id, transfers)
+		}
+
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(origin: OriginFor<T>, to: T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+			Self::ensure_origin_or_owner::<T::MintOrigin>(origin, asset_id)?;
+			Self::ensure_not_native(asset_id)?;
+			Self::do_mint(&to, asset_id, amount)
+		}
+
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, from: T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+			Self::ensure_origin_or_owner::<T::MintOrigin>(origin, asset_id)?;
+			Self::ensure_not_native(asset_id)?;
+			Self::burn_balance(&from, asset_id, amount, false)
+		}
+
+		#[pallet::weight(T::WeightInfo::burn_own())]
+		pub fn burn_own(origin: OriginFor<T>, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_native(asset_id)?;
+			Self::do_burn(&who, asset_id, amount)
+		}
+
+		/// Locks `amount` of native currency in the pallet account and mints as much
+		/// `NativeAssetId` to the caller.
+		#[pallet::weight(50_000 + T::DbWeight::get().reads_writes(3, 3))]
+		pub fn wrap(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::transfer(&who, &Self::account_id(), amount, ExistenceRequirement::KeepAlive)?;
+			Self::do_mint(&who, T::NativeAssetId::get(), amount)?;
+			Self::deposit_event(Event::Wrapped(who, amount));
+			Ok(())
+		}
+
+		/// Burns `amount` of the caller's `NativeAssetId` and releases as much native currency.
+		#[pallet::weight(50_000 + T::DbWeight::get().reads_writes(3, 3))]
+		pub fn unwrap(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_burn(&who, T::NativeAssetId::get(), amount)?;
+			T::Currency::transfer(&Self::account_id(), &who, amount, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::Unwrapped(who, amount));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8
+		) -> DispatchResult {
+			Self::ensure_origin_or_owner::<T::AdminOrigin>(origin, asset_id)?;
+			Self::do_set_metadata(asset_id, name, symbol, decimals)
+		}
+
+		/// Records the current balances of an asset under a new snapshot ID.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 1))]
+		pub fn snapshot(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_snapshot(asset_id)?;
+			Ok(())
+		}
+
+		/// Sets the fee charged on `transfer` and `transfer_from` of an asset and who receives it.
+		/// A zero rate disables the fee.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+		pub fn set_transfer_fee(origin: OriginFor<T>, asset_id: T::AssetId, fee_bps: u16, recipient: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			ensure!(fee_bps <= MAX_FEE_BPS, Error::<T>::InvalidFeeRate);
+			TransferFeeRate::<T>::insert(asset_id, fee_bps);
+			FeeRecipient::<T>::insert(asset_id, &recipient);
+			Self::deposit_event(Event::TransferFeeSet(asset_id, fee_bps, recipient));
+			Ok(())
+		}
+
+		/// Sets how many units of an asset pay for one unit of native transaction fee. `None` stops
+		/// the asset from paying transaction fees.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_fee_conversion_rate(origin: OriginFor<T>, asset_id: T::AssetId, rate: Option<FixedU128>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			FeeConversionRate::<T>::set(asset_id, rate);
+			Self::deposit_event(Event::FeeConversionRateSet(asset_id, rate));
+			Ok(())
+		}
+
+		/// Stops all movements of `asset_id`, or of every asset if `None`.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn pause(origin: OriginFor<T>, asset_id: Option<T::AssetId>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			match asset_id {
+				Some(asset_id) => {
+					Self::ensure_asset_exists(asset_id)?;
+					PausedAssets::<T>::insert(asset_id, ());
+				},
+				None => Paused::<T>::put(true),
+			}
+			Self::deposit_event(Event::Paused(asset_id));
+			Ok(())
+		}
+
+		/// Lifts a pause set by `pause` with the same `asset_id`.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn unpause(origin: OriginFor<T>, asset_id: Option<T::AssetId>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			match asset_id {
+				Some(asset_id) => PausedAssets::<T>::remove(asset_id),
+				None => Paused::<T>::kill(),
+			}
+			Self::deposit_event(Event::Unpaused(asset_id));
+			Ok(())
+		}
+
+		/// Stops `who` from sending tokens of `asset_id`.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn freeze_account(origin: OriginFor<T>, who: T::AccountId, asset_id: T::AssetId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			FrozenAccounts::<T>::insert(asset_id, &who, ());
+			Self::deposit_event(Event::AccountFrozen(asset_id, who));
+			Ok(())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn thaw_account(origin: OriginFor<T>, who: T::AccountId, asset_id: T::AssetId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			FrozenAccounts::<T>::remove(asset_id, &who);
+			Self::deposit_event(Event::AccountThawed(asset_id, who));
+			Ok(())
+		}
+
+		/// Sets an allowance from an approval signed off-chain by `owner`. Anyone may submit it.
+		/// The allowance can be used until `expiry`, like one set by `approve`.
+		///
+		/// The owner signs the bytes returned by `permit_payload`.
+		#[pallet::weight(50_000 + T::DbWeight::get().reads_writes(2, 2))]
+		#[allow(clippy::too_many_arguments)]
+		pub fn permit(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+			expiry: Option<T::BlockNumber>,
+			nonce: u64,
+			deadline: T::BlockNumber,
+			signature: T::OffchainSignature
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_asset_exists(asset_id)?;
+			ensure!(frame_system::Pallet::<T>::block_number() <= deadline, Error::<T>::PermitExpired);
+			ensure!(nonce == Self::permit_nonce(&owner), Error::<T>::InvalidPermitNonce);
+			let payload = Self::permit_payload(&owner, &spender, asset_id, amount, expiry, nonce, deadline);
+			ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidPermitSignature);
+
+			PermitNonces::<T>::insert(&owner, nonce.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			let epoch = Self::allowance_epoch(asset_id, &owner);
+			Allowances::<T>::insert((asset_id, owner.clone(), spender.clone()), Approval { amount, expiry, epoch });
+			Self::deposit_event(Event::Approved(asset_id, owner, spender, amount));
 			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	pub fn do_create_asset(
+		creator: &T::AccountId
+	) -> Result<T::AssetId, DispatchError> {
+		let asset_id = NextAssetId::<T>::try_mutate(|next_id| -> Result<T::AssetId, DispatchError>{
+			let id = *next_id;
+			*next_id = next_id.checked_add(&One::one()).ok_or(Error::<T>::NoAvailableAssetId)?;
+			Ok(id)
+		})?;
+		let new_asset: AssetInfoOf<T> = AssetInfo {
+			owner: creator.clone(),
+		};
+		Assets::<T>::insert(asset_id, new_asset);
+		Self::deposit_event(Event::<T>::AssetCreated(asset_id, creator.clone()));
+		Ok(asset_id)
+	}
+
+	pub fn do_set_metadata(
+		asset_id: T::AssetId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		let bounded_name: BoundedStringOf<T> = name.clone().try_into().map_err(|_err| Error::<T>::BadMetadata)?;
+		let bounded_symbol: BoundedStringOf<T> = symbol.clone().try_into().map_err(|_err| Error::<T>::BadMetadata)?;
+		Metadata::<T>::insert(asset_id, AssetMetadata {
+			name: bounded_name,
+			symbol: bounded_symbol,
+			decimals,
+		});
+		Self::deposit_event(Event::<T>::MetadataSet(asset_id, name, symbol, decimals));
+		Ok(())
+	}
+
+	/// Returns `(name, symbol, decimals)` of an asset, or `None` if the asset does not exist.
+	pub fn metadata_of(
+		asset_id: T::AssetId
+	) -> Option<(Vec<u8>, Vec<u8>, u8)> {
+		if !Assets::<T>::contains_key(asset_id) {
+			return None;
+		}
+		let metadata = Self::metadata(asset_id);
+		Some((metadata.name.into_inner(), metadata.symbol.into_inner(), metadata.decimals))
+	}
+
+	/// The message an owner signs to authorize a `permit`. It is bound to this chain by the
+	/// genesis hash.
+	pub fn permit_payload(
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance,
+		expiry: Option<T::BlockNumber>,
+		nonce: u64,
+		deadline: T::BlockNumber
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		(b"ft-permit", genesis_hash, owner, spender, asset_id, amount, expiry, nonce, deadline).encode()
+	}
+
+	/// The account holding the native currency backing `NativeAssetId`. It must be endowed with
+	/// the existential deposit at genesis, which is not counted as backing.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Succeeds if `origin` passes `O` or is signed by the owner of the asset.
+	pub fn ensure_origin_or_owner<O: EnsureOrigin<T::Origin>>(
+		origin: OriginFor<T>,
+		asset_id: T::AssetId
+	) -> DispatchResult {
+		let origin = match O::try_origin(origin) {
+			Ok(_) => return Ok(()),
+			Err(origin) => origin,
+		};
+		let who = ensure_signed(origin)?;
+		let asset = Self::assets(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+		ensure!(asset.owner == who, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	/// Fails for `NativeAssetId`, whose supply must always match the native currency held by
+	/// the pallet account.
+	pub fn ensure_not_native(
+		asset_id: T::AssetId
+	) -> DispatchResult {
+		ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetRestricted);
+		Ok(())
+	}
+
+	pub fn ensure_asset_exists(
+		asset_id: T::AssetId
+	) -> DispatchResult {
+		ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+		Ok(())
+	}
+
 	pub fn add_balance(
 		who: &T::AccountId,
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
 	) -> DispatchResult {
-		Balances::<T>::try_mutate(who, |balance| -> DispatchResult{
-			*balance = balance.checked_add(amount).ok_or(Error::<T>::Overflow)?;
-			Ok(())
-		})?;
+		Self::credit_balance(who, asset_id, amount, true)
+	}
+
+	/// Adds `amount` to the free balance of `who`. If `check_minimum` is false, the account may be
+	/// created with less than the minimum balance.
+	pub(crate) fn credit_balance(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance,
+		check_minimum: bool
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let new_balance = Self::balance(asset_id, who).checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+		ensure!(
+			!check_minimum
+				|| new_balance >= T::MinimumBalance::get()
+				|| Balances::<T>::contains_key(asset_id, who)
+				|| !Self::total_reserved_balance(who, asset_id).is_zero(),
+			Error::<T>::BelowMinimumBalance
+		);
+		Self::update_account_snapshot(who, asset_id);
+		Self::write_balance(who, asset_id, new_balance);
 		Ok(())
 	}
 
+	/// Subtracts `amount` from the free balance of `who`. The account is not reaped here; callers
+	/// call `reap_dust` once the movement has been recorded.
 	pub fn sub_balance(
 		who: &T::AccountId,
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
 	) -> DispatchResult {
-		Balances::<T>::try_mutate(who, |balance| -> DispatchResult{
-			*balance = balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
-			Ok(())
-		})?;
+		let new_balance = Self::balance(asset_id, who).checked_sub(&amount).ok_or(Error::<T>::InsufficientBalance)?;
+		ensure!(new_balance >= Self::locked_balance(who, asset_id), Error::<T>::LiquidityRestrictions);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::update_account_snapshot(who, asset_id);
+		Self::write_balance(who, asset_id, new_balance);
 		Ok(())
 	}
 
+	/// Writes the free balance of `who`, counting a new holder if the account had no entry.
+	pub(crate) fn write_balance(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		balance: T::Balance
+	) {
+		if !Balances::<T>::contains_key(asset_id, who) {
+			HolderCount::<T>::mutate(asset_id, |count| *count = count.saturating_add(1));
+		}
+		Balances::<T>::insert(asset_id, who, balance);
+	}
+
+	/// Removes `who` if its free balance is below the minimum and nothing is reserved or locked.
+	/// The remaining dust is burned through the transfer hooks and the allowances it granted are
+	/// voided. The account is kept if `TransferFilter` vetoes the burn. `NativeAssetId` is never
+	/// reaped, as burning it would strand its native backing.
+	pub(crate) fn reap_dust(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) {
+		let dust = Self::balance(asset_id, who);
+		if asset_id == T::NativeAssetId::get()
+			|| !Balances::<T>::contains_key(asset_id, who)
+			|| dust >= T::MinimumBalance::get()
+			|| !Self::total_reserved_balance(who, asset_id).is_zero()
+			|| !Self::locked_balance(who, asset_id).is_zero()
+		{
+			return;
+		}
+		if !dust.is_zero() && T::TransferFilter::filter(asset_id, Some(who), None, dust).is_err() {
+			return;
+		}
+		Self::update_account_snapshot(who, asset_id);
+		Balances::<T>::remove(asset_id, who);
+		HolderCount::<T>::mutate(asset_id, |count| *count = count.saturating_sub(1));
+		Self::void_allowances(who, asset_id);
+		if !dust.is_zero() {
+			Self::update_supply_snapshot(asset_id);
+			TotalSupply::<T>::mutate(asset_id, |total_supply| *total_supply = total_supply.saturating_sub(dust));
+			T::OnTransfer::on_transfer(asset_id, Some(who), None, dust);
+			Self::deposit_event(Event::<T>::DustLost(asset_id, who.clone(), dust));
+		}
+	}
+
 	pub fn add_supply(
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
 	) -> DispatchResult {
-		TotalSupply::<T>::try_mutate(|total_supply| -> DispatchResult{
-			*total_supply = total_supply.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+		Self::update_supply_snapshot(asset_id);
+		TotalSupply::<T>::try_mutate(asset_id, |total_supply| -> DispatchResult{
+			*total_supply = total_supply.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
 			Ok(())
 		})?;
 		Ok(())
 	}
 
 	pub fn sub_supply(
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
 	) -> DispatchResult {
-		TotalSupply::<T>::try_mutate(|total_supply| -> DispatchResult{
-			*total_supply = total_supply.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+		Self::update_supply_snapshot(asset_id);
+		TotalSupply::<T>::try_mutate(asset_id, |total_supply| -> DispatchResult{
+			*total_supply = total_supply.checked_sub(&amount).ok_or(Error::<T>::InsufficientBalance)?;
 			Ok(())
 		})?;
 		Ok(())
 	}
 
-	pub fn mint(
+	pub fn do_mint(
 		who: &T::AccountId,
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(None, asset_id)?;
+		T::TransferFilter::filter(asset_id, None, Some(who), amount)?;
+		Self::add_supply(asset_id, amount)?;
+		Self::add_balance(who, asset_id, amount)?;
+		T::OnTransfer::on_transfer(asset_id, None, Some(who), amount);
+		Self::deposit_event(Event::<T>::Minted(asset_id, who.clone(), amount, Self::total_supply(asset_id)));
+		Ok(())
+	}
+
+	pub fn is_paused(
+		asset_id: T::AssetId
+	) -> bool {
+		Self::paused() || PausedAssets::<T>::contains_key(asset_id)
+	}
+
+	pub fn is_account_frozen(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> bool {
+		FrozenAccounts::<T>::contains_key(asset_id, who)
+	}
+
+	/// Fails if `asset_id` is paused or if the account the tokens leave, if any, is frozen.
+	pub fn ensure_can_move(
+		from: Option<&T::AccountId>,
+		asset_id: T::AssetId
+	) -> DispatchResult {
+		ensure!(!Self::is_paused(asset_id), Error::<T>::AssetPaused);
+		if let Some(from) = from {
+			ensure!(!Self::is_account_frozen(from, asset_id), Error::<T>::AccountIsFrozen);
+		}
+		Ok(())
+	}
+
+	/// Moves `amount` from `from` to `to`, running the transfer hooks, then reaps `from`. Emits
+	/// no event for the transfer.
+	#[transactional]
+	pub fn do_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::move_balance(from, to, asset_id, amount)?;
+		Self::reap_dust(from, asset_id);
+		Ok(())
+	}
+
+	/// Like `do_transfer`, without reaping `from`.
+	#[transactional]
+	fn move_balance(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(Some(from), asset_id)?;
+		T::TransferFilter::filter(asset_id, Some(from), Some(to), amount)?;
+		Self::sub_balance(from, asset_id, amount)?;
+		Self::add_balance(to, asset_id, amount)?;
+		T::OnTransfer::on_transfer(asset_id, Some(from), Some(to), amount);
+		Ok(())
+	}
+
+	/// The fee charged when `from` sends `amount` to `to`, and who receives it.
+	pub fn transfer_fee(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> Option<(T::AccountId, T::Balance)> {
+		if T::FeeExempt::contains(from) || T::FeeExempt::contains(to) {
+			return None;
+		}
+		let recipient = Self::fee_recipient(asset_id)?;
+		let fee_bps = T::Balance::from(Self::transfer_fee_rate(asset_id));
+		let denominator = T::Balance::from(MAX_FEE_BPS);
+		let fee = (amount / denominator).saturating_mul(fee_bps)
+			.saturating_add((amount % denominator).saturating_mul(fee_bps) / denominator);
+		if fee.is_zero() {
+			return None;
+		}
+		Some((recipient, fee))
+	}
+
+	/// Like `do_transfer`, but sends the transfer fee of `amount` to the fee recipient and
+	/// emits the events.
+	///
+	/// The gross amount is withdrawn at once. The fee is credited even if it leaves the recipient
+	/// below the minimum balance.
+	#[transactional]
+	pub fn do_transfer_with_fee(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		match Self::transfer_fee(from, to, asset_id, amount) {
+			Some((recipient, fee)) => {
+				let net = amount.saturating_sub(fee);
+				Self::ensure_asset_exists(asset_id)?;
+				Self::ensure_can_move(Some(from), asset_id)?;
+				T::TransferFilter::filter(asset_id, Some(from), Some(to), net)?;
+				T::TransferFilter::filter(asset_id, Some(from), Some(&recipient), fee)?;
+				Self::sub_balance(from, asset_id, amount)?;
+				Self::add_balance(to, asset_id, net)?;
+				Self::credit_balance(&recipient, asset_id, fee, false)?;
+				T::OnTransfer::on_transfer(asset_id, Some(from), Some(to), net);
+				T::OnTransfer::on_transfer(asset_id, Some(from), Some(&recipient), fee);
+				Self::deposit_event(Event::Transferred(asset_id, from.clone(), to.clone(), net));
+				Self::deposit_event(Event::FeeCharged(asset_id, from.clone(), to.clone(), amount, net, fee));
+			},
+			None => {
+				Self::move_balance(from, to, asset_id, amount)?;
+				Self::deposit_event(Event::Transferred(asset_id, from.clone(), to.clone(), amount));
+			},
+		}
+		Self::reap_dust(from, asset_id);
+		Ok(())
+	}
+
+	/// Sum of the amounts of a batch.
+	pub fn batch_total(
+		transfers: &BatchTransfersOf<T>
+	) -> Result<T::Balance, DispatchError> {
+		transfers.iter().try_fold(Zero::zero(), |total: T::Balance, (_, amount)| {
+			total.checked_add(amount).ok_or_else(|| Error::<T>::Overflow.into())
+		})
+	}
+
+	pub fn do_batch_transfer(
+		from: &T::AccountId,
+		asset_id: T::AssetId,
+		transfers: BatchTransfersOf<T>
 	) -> DispatchResult {
-		Self::add_supply(amount)?;
-		Self::add_balance(who, amount)?;
+		let total = Self::batch_total(&transfers)?;
+		for (to, amount) in transfers.iter() {
+			Self::do_transfer_with_fee(from, to, asset_id, *amount)?;
+		}
+		Self::deposit_event(Event::<T>::BatchTransferred(asset_id, from.clone(), transfers.len() as u32, total));
 		Ok(())
 	}
 
-	pub fn burn(
+	pub fn do_burn(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::burn_balance(who, asset_id, amount, true)
+	}
+
+	/// Burns `amount` from the free balance of `who`. If `check_frozen` is false, the tokens are
+	/// burned even if the account is frozen.
+	pub(crate) fn burn_balance(
 		who: &T::AccountId,
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance,
+		check_frozen: bool
 	) -> DispatchResult {
-		Self::sub_supply(amount)?;
-		Self::sub_balance(who, amount)?;
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(if check_frozen { Some(who) } else { None }, asset_id)?;
+		T::TransferFilter::filter(asset_id, Some(who), None, amount)?;
+		Self::sub_balance(who, asset_id, amount)?;
+		Self::sub_supply(asset_id, amount)?;
+		T::OnTransfer::on_transfer(asset_id, Some(who), None, amount);
+		Self::deposit_event(Event::<T>::Burned(asset_id, who.clone(), amount, Self::total_supply(asset_id)));
+		Self::reap_dust(who, asset_id);
 		Ok(())
 	}
 
 	pub fn sub_allowance(
 		owner: &T::AccountId,
 		spender: &T::AccountId,
-		amount: u64
+		asset_id: T::AssetId,
+		amount: T::Balance
 	) -> DispatchResult {
-		Allowances::<T>::try_mutate(owner, spender, |allowance| -> DispatchResult{
-			*allowance = allowance.checked_sub(amount).ok_or(Error::<T>::InsufficientAllowance)?;
+		let epoch = Self::allowance_epoch(asset_id, owner);
+		Allowances::<T>::try_mutate((asset_id, owner.clone(), spender.clone()), |approval| -> DispatchResult{
+			Self::refresh_approval(approval, epoch);
+			ensure!(!Self::is_expired(approval), Error::<T>::ApprovalExpired);
+			approval.amount = approval.amount.checked_sub(&amount).ok_or(Error::<T>::InsufficientAllowance)?;
 			Ok(())
 		})?;
 		Ok(())
 	}
+
+	/// The amount `spender` may currently transfer from `owner`, zero if the allowance expired
+	/// or was voided by reaping the owner.
+	pub fn allowance_of(
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		let approval = Self::allowance((asset_id, owner.clone(), spender.clone()));
+		if approval.epoch != Self::allowance_epoch(asset_id, owner) || Self::is_expired(&approval) {
+			return Zero::zero();
+		}
+		approval.amount
+	}
+
+	/// Voids every allowance `owner` has granted for an asset in O(1). The stale entries stay in
+	/// storage until they are overwritten or removed by `revoke_all`.
+	pub(crate) fn void_allowances(
+		owner: &T::AccountId,
+		asset_id: T::AssetId
+	) {
+		AllowanceEpochs::<T>::mutate(asset_id, owner, |epoch| *epoch = epoch.wrapping_add(1));
+	}
+
+	/// Resets an allowance granted in an earlier epoch to an empty one of `epoch`.
+	fn refresh_approval(
+		approval: &mut ApprovalOf<T>,
+		epoch: u32
+	) {
+		if approval.epoch != epoch {
+			*approval = Approval { epoch, ..Default::default() };
+		}
+	}
+
+	fn is_expired(
+		approval: &ApprovalOf<T>
+	) -> bool {
+		match approval.expiry {
+			Some(expiry) => frame_system::Pallet::<T>::block_number() > expiry,
+			None => false,
+		}
+	}
+}
+
+impl <T: Config> FTTransfer<T::AccountId, T::AssetId, T::Balance> for Pallet<T>{
+	fn transfer(src: &T::AccountId, dst: &T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+		Self::do_transfer(src, dst, asset_id, amount)
+	}
 }
 
-impl <T: Config> FTTransfer<T::AccountId> for Pallet<T>{
-	fn transfer(src: &T::AccountId, dst: &T::AccountId, amount: u64) -> DispatchResult {
-		Self::sub_balance(src, amount)?;
-		Self::add_balance(dst, amount)?;
+impl<T: Config> Pallet<T> {
+	pub fn reserved_balance(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		Self::reserves(asset_id, who)
+			.iter()
+			.find(|reserve| reserve.id == *id)
+			.map(|reserve| reserve.amount)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	pub fn total_reserved_balance(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		Self::reserves(asset_id, who)
+			.iter()
+			.fold(Zero::zero(), |total: T::Balance, reserve| total.saturating_add(reserve.amount))
+	}
+
+	/// The amount of `Balances` that cannot be moved, i.e. the largest freeze of the account.
+	pub fn frozen_balance(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		Self::freezes(asset_id, who)
+			.iter()
+			.map(|freeze| freeze.amount)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// The amount of `Balances` that cannot be moved because of freezes or vesting.
+	pub fn locked_balance(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		Self::frozen_balance(who, asset_id).max(Self::vesting_lock(asset_id, who))
+	}
+
+	/// Recomputes the vesting lock of `who`, dropping finished schedules. Returns the amount
+	/// still locked.
+	pub fn update_vesting_lock(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut schedules = Self::vesting(asset_id, who);
+		schedules.retain(|schedule| !schedule.locked_at::<T::BlockNumberToBalance>(now).is_zero());
+		let still_locked = schedules
+			.iter()
+			.fold(Zero::zero(), |total: T::Balance, schedule| {
+				total.saturating_add(schedule.locked_at::<T::BlockNumberToBalance>(now))
+			});
+		if schedules.is_empty() {
+			Vesting::<T>::remove(asset_id, who);
+			VestingLocks::<T>::remove(asset_id, who);
+		} else {
+			Vesting::<T>::insert(asset_id, who, schedules);
+			VestingLocks::<T>::insert(asset_id, who, still_locked);
+		}
+		still_locked
+	}
+
+	#[transactional]
+	pub fn do_reserve(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(Some(who), asset_id)?;
+		// Reserve first so that moving the whole free balance does not reap the account.
+		Self::add_reserve(id, who, asset_id, amount)?;
+		Self::sub_balance(who, asset_id, amount)?;
+		Self::deposit_event(Event::<T>::Reserved(asset_id, who.clone(), amount));
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_unreserve(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(None, asset_id)?;
+		Self::add_balance(who, asset_id, amount)?;
+		Self::sub_reserve(id, who, asset_id, amount)?;
+		Self::deposit_event(Event::<T>::Unreserved(asset_id, who.clone(), amount));
+		Ok(())
+	}
+
+	/// Moves `amount` from the named reserve of `slashed` to `beneficiary`. With
+	/// `BalanceStatus::Reserved` the tokens land in the beneficiary's reserve under the same `id`.
+	#[transactional]
+	pub fn do_repatriate_reserved(
+		id: &T::ReserveIdentifier,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance,
+		status: BalanceStatus
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::ensure_can_move(Some(slashed), asset_id)?;
+		T::TransferFilter::filter(asset_id, Some(slashed), Some(beneficiary), amount)?;
+		Self::sub_reserve(id, slashed, asset_id, amount)?;
+		match status {
+			BalanceStatus::Free => Self::add_balance(beneficiary, asset_id, amount)?,
+			BalanceStatus::Reserved => {
+				// A free entry counts the beneficiary as a holder and lets it be reaped once the
+				// reserve is gone.
+				if !Balances::<T>::contains_key(asset_id, beneficiary) {
+					Self::write_balance(beneficiary, asset_id, Zero::zero());
+				}
+				Self::add_reserve(id, beneficiary, asset_id, amount)?
+			},
+		}
+		T::OnTransfer::on_transfer(asset_id, Some(slashed), Some(beneficiary), amount);
+		Self::deposit_event(Event::<T>::ReserveRepatriated(asset_id, slashed.clone(), beneficiary.clone(), amount, status));
+		Self::reap_dust(slashed, asset_id);
+		Ok(())
+	}
+
+	/// Sets the named freeze of `who` to `amount`, replacing any previous amount.
+	pub fn do_set_freeze(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		if amount.is_zero() {
+			return Self::do_thaw(id, who, asset_id);
+		}
+		Freezes::<T>::try_mutate(asset_id, who, |freezes| -> DispatchResult{
+			if let Some(index) = freezes.iter().position(|freeze| freeze.id == *id) {
+				freezes[index].amount = amount;
+			} else {
+				freezes.try_push(IdAmount { id: *id, amount }).map_err(|_err| Error::<T>::TooManyFreezes)?;
+			}
+			Ok(())
+		})?;
+		Self::deposit_event(Event::<T>::FreezeSet(asset_id, who.clone(), amount));
+		Ok(())
+	}
+
+	pub fn do_thaw(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Freezes::<T>::mutate(asset_id, who, |freezes| freezes.retain(|freeze| freeze.id != *id));
+		Self::deposit_event(Event::<T>::Thawed(asset_id, who.clone()));
 		Ok(())
 	}
+
+	fn add_reserve(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Reserves::<T>::try_mutate(asset_id, who, |reserves| -> DispatchResult{
+			if let Some(index) = reserves.iter().position(|reserve| reserve.id == *id) {
+				reserves[index].amount = reserves[index].amount.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			} else {
+				reserves.try_push(IdAmount { id: *id, amount }).map_err(|_err| Error::<T>::TooManyReserves)?;
+			}
+			Ok(())
+		})
+	}
+
+	fn sub_reserve(
+		id: &T::ReserveIdentifier,
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance
+	) -> DispatchResult {
+		Reserves::<T>::try_mutate(asset_id, who, |reserves| -> DispatchResult{
+			let index = reserves.iter().position(|reserve| reserve.id == *id).ok_or(Error::<T>::InsufficientReserve)?;
+			let remaining = reserves[index].amount.checked_sub(&amount).ok_or(Error::<T>::InsufficientReserve)?;
+			if remaining.is_zero() {
+				reserves.remove(index);
+			} else {
+				reserves[index].amount = remaining;
+			}
+			Ok(())
+		})
+	}
+}
+
+impl<T: Config> FTReserve<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+	type ReserveIdentifier = T::ReserveIdentifier;
+
+	fn reserved_balance(id: &T::ReserveIdentifier, who: &T::AccountId, asset_id: T::AssetId) -> T::Balance {
+		Self::reserved_balance(id, who, asset_id)
+	}
+
+	fn reserve(id: &T::ReserveIdentifier, who: &T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+		Self::do_reserve(id, who, asset_id, amount)
+	}
+
+	fn unreserve(id: &T::ReserveIdentifier, who: &T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+		Self::do_unreserve(id, who, asset_id, amount)
+	}
+
+	fn repatriate_reserved(
+		id: &T::ReserveIdentifier,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		asset_id: T::AssetId,
+		amount: T::Balance,
+		status: BalanceStatus,
+	) -> DispatchResult {
+		Self::do_repatriate_reserved(id, slashed, beneficiary, asset_id, amount, status)
+	}
+
+	fn frozen_balance(who: &T::AccountId, asset_id: T::AssetId) -> T::Balance {
+		Self::frozen_balance(who, asset_id)
+	}
+
+	fn set_freeze(id: &T::ReserveIdentifier, who: &T::AccountId, asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+		Self::do_set_freeze(id, who, asset_id, amount)
+	}
+
+	fn thaw(id: &T::ReserveIdentifier, who: &T::AccountId, asset_id: T::AssetId) -> DispatchResult {
+		Self::do_thaw(id, who, asset_id)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn do_snapshot(
+		asset_id: T::AssetId
+	) -> Result<SnapshotId, DispatchError> {
+		Self::ensure_asset_exists(asset_id)?;
+		let snapshot_id = CurrentSnapshotId::<T>::try_mutate(asset_id, |current_id| -> Result<SnapshotId, DispatchError> {
+			*current_id = current_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			Ok(*current_id)
+		})?;
+		Self::deposit_event(Event::<T>::SnapshotTaken(asset_id, snapshot_id));
+		Ok(snapshot_id)
+	}
+
+	/// Balance of `who` when `snapshot_id` was taken, or `None` if no such snapshot exists or it
+	/// is older than the last `MaxSnapshotCheckpoints` checkpoints of the account.
+	///
+	/// A checkpoint is only written when a balance changes, so the value is the first checkpoint
+	/// at or after `snapshot_id`, falling back to the current balance.
+	pub fn balance_at(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		snapshot_id: SnapshotId
+	) -> Option<T::Balance> {
+		let current_id = Self::current_snapshot_id(asset_id);
+		if snapshot_id == 0 || snapshot_id > current_id {
+			return None;
+		}
+		let recorded = Self::checkpoint_at(&AccountCheckpoints::<T>::get(asset_id, who), snapshot_id)?;
+		Some(recorded.unwrap_or_else(|| Self::balance(asset_id, who)))
+	}
+
+	/// Total supply of an asset when `snapshot_id` was taken, or `None` if no such snapshot exists
+	/// or it is older than the last `MaxSnapshotCheckpoints` checkpoints of the supply.
+	pub fn total_supply_at(
+		asset_id: T::AssetId,
+		snapshot_id: SnapshotId
+	) -> Option<T::Balance> {
+		let current_id = Self::current_snapshot_id(asset_id);
+		if snapshot_id == 0 || snapshot_id > current_id {
+			return None;
+		}
+		let recorded = Self::checkpoint_at(&SupplyCheckpoints::<T>::get(asset_id), snapshot_id)?;
+		Some(recorded.unwrap_or_else(|| Self::total_supply(asset_id)))
+	}
+
+	/// Looks up the first checkpoint at or after `snapshot_id`. Returns `None` if the snapshot
+	/// was pruned and `Some(None)` if the value has not changed since.
+	fn checkpoint_at(
+		checkpoints: &CheckpointsOf<T>,
+		snapshot_id: SnapshotId
+	) -> Option<Option<T::Balance>> {
+		let (pruned_until, entries) = checkpoints;
+		if snapshot_id <= *pruned_until {
+			return None;
+		}
+		let index = entries.partition_point(|(id, _)| *id < snapshot_id);
+		Some(entries.get(index).map(|(_, value)| *value))
+	}
+
+	/// Appends `value` under `current_id` unless a checkpoint was already taken for it, dropping
+	/// the oldest checkpoint when full.
+	fn push_checkpoint(
+		checkpoints: &mut CheckpointsOf<T>,
+		current_id: SnapshotId,
+		value: T::Balance
+	) -> bool {
+		let (pruned_until, entries) = checkpoints;
+		if entries.last().map_or(false, |(id, _)| *id == current_id) {
+			return false;
+		}
+		if entries.len() as u32 >= T::MaxSnapshotCheckpoints::get() {
+			if entries.is_empty() {
+				*pruned_until = current_id;
+				return true;
+			}
+			let (oldest_id, _) = entries.remove(0);
+			*pruned_until = oldest_id;
+		}
+		let _ = entries.try_push((current_id, value));
+		true
+	}
+
+	/// Records the balance of `who` under the current snapshot before it changes.
+	pub(crate) fn update_account_snapshot(
+		who: &T::AccountId,
+		asset_id: T::AssetId
+	) {
+		let current_id = Self::current_snapshot_id(asset_id);
+		if current_id == 0 {
+			return;
+		}
+		let mut checkpoints = AccountCheckpoints::<T>::get(asset_id, who);
+		if Self::push_checkpoint(&mut checkpoints, current_id, Self::balance(asset_id, who)) {
+			AccountCheckpoints::<T>::insert(asset_id, who, checkpoints);
+		}
+	}
+
+	/// Records the total supply under the current snapshot before it changes.
+	pub(crate) fn update_supply_snapshot(
+		asset_id: T::AssetId
+	) {
+		let current_id = Self::current_snapshot_id(asset_id);
+		if current_id == 0 {
+			return;
+		}
+		let mut checkpoints = SupplyCheckpoints::<T>::get(asset_id);
+		if Self::push_checkpoint(&mut checkpoints, current_id, Self::total_supply(asset_id)) {
+			SupplyCheckpoints::<T>::insert(asset_id, checkpoints);
+		}
+	}
+}
+
+impl<T: Config> FTSnapshot<T::AccountId, T::AssetId, T::Balance> for Pallet<T> {
+	type SnapshotId = SnapshotId;
+
+	fn snapshot(asset_id: T::AssetId) -> Result<SnapshotId, DispatchError> {
+		Self::do_snapshot(asset_id)
+	}
+
+	fn balance_at(who: &T::AccountId, asset_id: T::AssetId, snapshot_id: SnapshotId) -> Option<T::Balance> {
+		Self::balance_at(who, asset_id, snapshot_id)
+	}
+
+	fn total_supply_at(asset_id: T::AssetId, snapshot_id: SnapshotId) -> Option<T::Balance> {
+		Self::total_supply_at(asset_id, snapshot_id)
+	}
 }
@@ -0,0 +1,99 @@
+//! Storage migrations of the fungible token pallet.
+
+use super::*;
+
+/// Version 1 keeps balances, total supplies and allowances per asset. The single token of
+/// version 0 becomes the asset `AssetId::default()`, owned by the pallet account.
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		log,
+		storage::migration::{storage_iter, take_storage_value},
+		traits::{OnRuntimeUpgrade, PalletInfoAccess},
+		ReversibleStorageHasher, Twox64Concat,
+	};
+	use sp_runtime::SaturatedConversion;
+	use sp_std::marker::PhantomData;
+
+	/// Balance type of version 0.
+	pub type OldBalance = u64;
+
+	/// Runs `migrate` as a runtime migration, for runtimes whose other migrations need the
+	/// balances in the version 1 layout before the pallet hooks run.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate::<T>()
+		}
+	}
+
+	/// Moves every balance, the total supply and every allowance of version 0 under the asset
+	/// `AssetId::default()` and creates that asset. Allowances never expire and belong to the
+	/// current allowance epoch of their owner.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let pallet_name = Pallet::<T>::name().as_bytes();
+		let asset_id = T::AssetId::default();
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+
+		// The new maps share the storage prefix of the old ones, so the old entries are all
+		// taken out before the new ones are written.
+		let balances = storage_iter::<OldBalance>(pallet_name, b"Balances").drain().collect::<Vec<_>>();
+		let allowances = storage_iter::<OldBalance>(pallet_name, b"Allowances").drain().collect::<Vec<_>>();
+		let total_supply = take_storage_value::<OldBalance>(pallet_name, b"TotalSupply", &[]).unwrap_or_default();
+		reads += balances.len() as Weight + allowances.len() as Weight + 1;
+		writes += balances.len() as Weight + allowances.len() as Weight + 1;
+
+		for (key, balance) in balances {
+			let who = match T::AccountId::decode(&mut Twox64Concat::reverse(&key)) {
+				Ok(who) => who,
+				Err(_) => {
+					log::warn!(target: "runtime::ft", "skipped an undecodable balance key {:?}", key);
+					continue;
+				},
+			};
+			if balance == 0 {
+				continue;
+			}
+			Balances::<T>::insert(asset_id, &who, balance.saturated_into::<T::Balance>());
+			HolderCount::<T>::mutate(asset_id, |count| *count = count.saturating_add(1));
+			writes += 2;
+		}
+
+		for (key, amount) in allowances {
+			let mut spender_key = Twox64Concat::reverse(&key);
+			let owner = T::AccountId::decode(&mut spender_key);
+			let spender = T::AccountId::decode(&mut Twox64Concat::reverse(spender_key));
+			let (owner, spender) = match (owner, spender) {
+				(Ok(owner), Ok(spender)) => (owner, spender),
+				_ => {
+					log::warn!(target: "runtime::ft", "skipped an undecodable allowance key {:?}", key);
+					continue;
+				},
+			};
+			if amount == 0 {
+				continue;
+			}
+			let approval = Approval { amount: amount.saturated_into::<T::Balance>(), expiry: None, epoch: 0 };
+			Allowances::<T>::insert((asset_id, owner, spender), approval);
+			writes += 1;
+		}
+
+		TotalSupply::<T>::insert(asset_id, total_supply.saturated_into::<T::Balance>());
+		Assets::<T>::insert(asset_id, AssetInfo { owner: Pallet::<T>::account_id() });
+		NextAssetId::<T>::mutate(|next_id| {
+			if *next_id <= asset_id {
+				*next_id = asset_id.saturating_add(One::one());
+			}
+		});
+		writes += 3;
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -0,0 +1,213 @@
+//! Mocks for the fungible token module.
+
+#![cfg(test)]
+
+use frame_support::{
+	construct_runtime,
+	parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Everything, IsInVec},
+	weights::IdentityFee,
+	PalletId,
+};
+use pallet_transaction_payment::CurrencyAdapter;
+use frame_system::EnsureRoot;
+
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestSignature, UintAuthorityId},
+	traits::{ConvertInto, IdentityLookup},
+	BuildStorage,
+};
+
+use super::*;
+
+use crate as ft;
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+pub type Balance = u128;
+pub type AssetId = u32;
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+	type OnChargeTransaction = payment::FungibleTokenAdapter<Runtime, ConstU32<ASSET_ID>, CurrencyAdapter<NativeBalances, ()>>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<Balance>;
+	type LengthToFee = IdentityFee<Balance>;
+	type FeeMultiplierUpdate = ();
+}
+
+parameter_types! {
+	pub const StringLimit: u32 = 8;
+	pub const FtPalletId: PalletId = PalletId(*b"ft/wrapn");
+	pub static FtMinimumBalance: Balance = 1;
+	pub FeeExemptAccounts: Vec<AccountId> = vec![EXEMPT];
+	pub static TransferLog: Vec<(AssetId, Option<AccountId>, Option<AccountId>, Balance)> = vec![];
+}
+
+/// Records every balance move in `TransferLog`.
+pub struct RecordTransfers;
+impl OnTransfer<AccountId, AssetId, Balance> for RecordTransfers {
+	fn on_transfer(asset_id: AssetId, from: Option<&AccountId>, to: Option<&AccountId>, amount: Balance) {
+		TransferLog::mutate(|log| log.push((asset_id, from.copied(), to.copied(), amount)));
+	}
+}
+
+/// Rejects any balance move involving `BLOCKED`.
+pub struct BlockList;
+impl TransferFilter<AccountId, AssetId, Balance> for BlockList {
+	fn filter(_asset_id: AssetId, from: Option<&AccountId>, to: Option<&AccountId>, _amount: Balance) -> DispatchResult {
+		if from == Some(&BLOCKED) || to == Some(&BLOCKED) {
+			return Err(DispatchError::Other("Blocked"));
+		}
+		Ok(())
+	}
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MintOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type StringLimit = StringLimit;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type BlockNumberToBalance = ConvertInto;
+	type MaxVestingSchedules = ConstU32<2>;
+	type MinVestedTransfer = ConstU128<10>;
+	type OnTransfer = RecordTransfers;
+	type TransferFilter = BlockList;
+	type FeeExempt = IsInVec<FeeExemptAccounts>;
+	type Currency = NativeBalances;
+	type NativeAssetId = ConstU32<WRAPPED_ASSET_ID>;
+	type PalletId = FtPalletId;
+	type MinimumBalance = FtMinimumBalance;
+	type MaxSnapshotCheckpoints = ConstU32<2>;
+	type MaxBatchTransfers = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		NativeBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		FT: ft::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const BLOCKED: AccountId = 4;
+pub const EXEMPT: AccountId = 5;
+pub const TREASURY: AccountId = 6;
+pub const ASSET_ID: AssetId = 0;
+pub const WRAPPED_ASSET_ID: AssetId = 1;
+pub const ASSET_ID_NOT_EXIST: AssetId = 100;
+
+/// Builds the test externalities. Asset `ASSET_ID` is always created, owned by `ALICE` and
+/// endowed with the configured genesis balances. Asset `WRAPPED_ASSET_ID` is always created
+/// with no balances.
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+	native_balances: Vec<(AccountId, Balance)>,
+	minimum_balance: Balance,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder {
+			balances: vec![],
+			native_balances: vec![],
+			minimum_balance: 1,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn minimum_balance(mut self, minimum_balance: Balance) -> Self {
+		self.minimum_balance = minimum_balance;
+		self
+	}
+
+	pub fn native_balances(mut self, native_balances: Vec<(AccountId, Balance)>) -> Self {
+		self.native_balances = native_balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		FtMinimumBalance::set(self.minimum_balance);
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		GenesisConfig {
+			ft: FTConfig {
+				assets: vec![
+					(ALICE, b"Token".to_vec(), b"TKN".to_vec(), 12, self.balances),
+					(ALICE, b"Wrapped".to_vec(), b"WNAT".to_vec(), 12, vec![]),
+				]
+			},
+			native_balances: NativeBalancesConfig {
+				balances: self.native_balances,
+			},
+			..Default::default()
+		}
+			.assimilate_storage(&mut t).unwrap();
+		TransferLog::set(vec![]);
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,110 @@
+//! An `OnChargeTransaction` adapter that lets accounts without enough native currency pay
+//! transaction fees in a fungible token.
+//!
+//! The native fee is converted at the rate stored in `FeeConversionRate` for the fee asset.
+//! Assets without a rate cannot be used to pay fees.
+
+use super::*;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	FixedPointNumber,
+};
+use sp_std::marker::PhantomData;
+
+/// How a transaction fee was paid.
+pub enum FeeLiquidity<NativeLiquidity, Balance> {
+	/// Paid by the native adapter.
+	Native(NativeLiquidity),
+	/// Paid in the fee asset. Holds the amount taken from the account.
+	Token(Balance),
+}
+
+/// Charges fees with `NativeAdapter` and falls back to charging them in `FeeAssetId` when the
+/// account cannot pay in native currency. Unused token fees are refunded after dispatch, the
+/// rest is burned. Token fees pass through `TransferFilter` and `OnTransfer` like any burn.
+///
+/// Token fees are withdrawn with keep-alive semantics: an account that would drop below
+/// `MinimumBalance` cannot pay in tokens, so it is never reaped and its refund always lands.
+pub struct FungibleTokenAdapter<T, FeeAssetId, NativeAdapter>(PhantomData<(T, FeeAssetId, NativeAdapter)>);
+
+impl<T, FeeAssetId, NativeAdapter> FungibleTokenAdapter<T, FeeAssetId, NativeAdapter>
+where
+	T: Config + pallet_transaction_payment::Config,
+	FeeAssetId: Get<T::AssetId>,
+{
+	/// The fee in `FeeAssetId` for a native fee, or `None` if the asset has no conversion rate.
+	/// `NativeAssetId` cannot pay fees, as burning it would strand its native backing.
+	pub fn token_fee(native_fee: <T as Config>::Balance) -> Option<<T as Config>::Balance> {
+		if FeeAssetId::get() == T::NativeAssetId::get() {
+			return None;
+		}
+		Pallet::<T>::fee_conversion_rate(FeeAssetId::get()).map(|rate| rate.saturating_mul_int(native_fee))
+	}
+}
+
+impl<T, FeeAssetId, NativeAdapter> OnChargeTransaction<T> for FungibleTokenAdapter<T, FeeAssetId, NativeAdapter>
+where
+	T: Config + pallet_transaction_payment::Config,
+	FeeAssetId: Get<T::AssetId>,
+	NativeAdapter: OnChargeTransaction<T, Balance = <T as Config>::Balance>,
+{
+	type Balance = <T as Config>::Balance;
+	type LiquidityInfo = Option<FeeLiquidity<NativeAdapter::LiquidityInfo, Self::Balance>>;
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::Call,
+		dispatch_info: &DispatchInfoOf<T::Call>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if fee.is_zero() {
+			return Ok(None);
+		}
+		if let Ok(liquidity) = NativeAdapter::withdraw_fee(who, call, dispatch_info, fee, tip) {
+			return Ok(Some(FeeLiquidity::Native(liquidity)));
+		}
+
+		let asset_id = FeeAssetId::get();
+		let token_fee = Self::token_fee(fee).ok_or(InvalidTransaction::Payment)?;
+		Pallet::<T>::ensure_can_move(Some(who), asset_id).map_err(|_err| InvalidTransaction::Payment)?;
+		T::TransferFilter::filter(asset_id, Some(who), None, token_fee).map_err(|_err| InvalidTransaction::Payment)?;
+		let remaining = Pallet::<T>::balance(asset_id, who).checked_sub(&token_fee).ok_or(InvalidTransaction::Payment)?;
+		ensure!(remaining >= T::MinimumBalance::get(), InvalidTransaction::Payment);
+		Pallet::<T>::sub_balance(who, asset_id, token_fee).map_err(|_err| InvalidTransaction::Payment)?;
+		Ok(Some(FeeLiquidity::Token(token_fee)))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::Call>,
+		post_info: &PostDispatchInfoOf<T::Call>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		match already_withdrawn {
+			Some(FeeLiquidity::Native(liquidity)) => NativeAdapter::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				liquidity,
+			),
+			Some(FeeLiquidity::Token(paid)) => {
+				let asset_id = FeeAssetId::get();
+				let corrected_token_fee = Self::token_fee(corrected_fee).unwrap_or(paid).min(paid);
+				let refund = paid.saturating_sub(corrected_token_fee);
+				Pallet::<T>::add_balance(who, asset_id, refund).map_err(|_err| InvalidTransaction::Payment)?;
+				Pallet::<T>::sub_supply(asset_id, corrected_token_fee).map_err(|_err| InvalidTransaction::Payment)?;
+				T::OnTransfer::on_transfer(asset_id, Some(who), None, corrected_token_fee);
+				Pallet::<T>::deposit_event(Event::<T>::TransactionFeePaid(asset_id, who.clone(), corrected_token_fee, refund));
+				Ok(())
+			},
+			None => Ok(()),
+		}
+	}
+}
@@ -0,0 +1,1025 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	parameter_types,
+	traits::tokens::{fungible, fungibles, BalanceStatus, DepositConsequence, WithdrawConsequence},
+};
+use mock::*;
+use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::BadOrigin,
+	transaction_validity::InvalidTransaction,
+	FixedPointNumber,
+};
+
+#[test]
+fn genesis_should_set_total_supply() {
+	ExtBuilder::default().balances(vec![(ALICE, 100), (BOB, 50)]).build().execute_with(|| {
+		assert_eq!(FT::next_asset_id(), 2);
+		assert_eq!(FT::assets(ASSET_ID).map(|asset| asset.owner), Some(ALICE));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 100);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 50);
+		assert_eq!(FT::total_supply(ASSET_ID), 150);
+		assert_eq!(FT::total_supply(WRAPPED_ASSET_ID), 0);
+		assert_eq!(FT::metadata_of(ASSET_ID), Some((b"Token".to_vec(), b"TKN".to_vec(), 12)));
+	});
+}
+
+#[test]
+fn asset_owner_should_mint_and_set_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(FT::create_asset(Origin::signed(BOB)));
+		System::assert_last_event(mock::Event::FT(crate::Event::AssetCreated(2, BOB)));
+		assert_ok!(FT::mint(Origin::signed(BOB), CHARLIE, 2, 10));
+		assert_eq!(FT::balance(2, CHARLIE), 10);
+		assert_ok!(FT::set_metadata(Origin::signed(BOB), 2, b"Bob".to_vec(), b"BOB".to_vec(), 6));
+		assert_eq!(FT::metadata_of(2), Some((b"Bob".to_vec(), b"BOB".to_vec(), 6)));
+
+		assert_noop!(FT::mint(Origin::signed(ALICE), CHARLIE, 2, 10), Error::<Runtime>::NoPermission);
+		assert_noop!(
+			FT::set_metadata(Origin::signed(ALICE), 2, b"Bob".to_vec(), b"BOB".to_vec(), 6),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(FT::mint(Origin::signed(BOB), CHARLIE, ASSET_ID_NOT_EXIST, 10), Error::<Runtime>::AssetNotFound);
+		assert_ok!(FT::mint(Origin::root(), CHARLIE, 2, 10));
+		assert_eq!(FT::total_supply(2), 20);
+	});
+}
+
+#[test]
+fn asset_owner_should_burn_from_frozen_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(FT::create_asset(Origin::signed(BOB)));
+		assert_ok!(FT::mint(Origin::signed(BOB), CHARLIE, 2, 30));
+		assert_ok!(FT::freeze_account(Origin::root(), CHARLIE, 2));
+		assert_noop!(FT::burn_own(Origin::signed(CHARLIE), 2, 10), Error::<Runtime>::AccountIsFrozen);
+
+		assert_noop!(FT::burn(Origin::signed(ALICE), CHARLIE, 2, 10), Error::<Runtime>::NoPermission);
+		assert_ok!(FT::burn(Origin::signed(BOB), CHARLIE, 2, 10));
+		System::assert_last_event(mock::Event::FT(crate::Event::Burned(2, CHARLIE, 10, 20)));
+		assert_ok!(FT::burn(Origin::root(), CHARLIE, 2, 10));
+		assert_eq!(FT::balance(2, CHARLIE), 10);
+		assert_eq!(FT::total_supply(2), 10);
+	});
+}
+
+#[test]
+fn transfer_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 40));
+		System::assert_last_event(mock::Event::FT(crate::Event::Transferred(ASSET_ID, ALICE, BOB, 40)));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 60);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 40);
+		assert_eq!(FT::total_supply(ASSET_ID), 100);
+	});
+}
+
+#[test]
+fn transfer_should_fail() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_noop!(
+			FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 101),
+			Error::<Runtime>::InsufficientBalance
+		);
+		assert_noop!(
+			FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID_NOT_EXIST, 1),
+			Error::<Runtime>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn approve_and_transfer_from_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 50, None));
+		System::assert_last_event(mock::Event::FT(crate::Event::Approved(ASSET_ID, ALICE, BOB, 50)));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 50);
+
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 30));
+		System::assert_last_event(mock::Event::FT(crate::Event::Transferred(ASSET_ID, ALICE, CHARLIE, 30)));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 20);
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 70);
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 30);
+	});
+}
+
+#[test]
+fn transfer_from_should_fail_on_allowance_underflow() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_noop!(
+			FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 1),
+			Error::<Runtime>::InsufficientAllowance
+		);
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 10, None));
+		assert_noop!(
+			FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 11),
+			Error::<Runtime>::InsufficientAllowance
+		);
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 10);
+	});
+}
+
+#[test]
+fn add_balance_should_fail_on_overflow() {
+	ExtBuilder::default().balances(vec![(ALICE, Balance::MAX)]).build().execute_with(|| {
+		assert_noop!(FT::add_balance(&ALICE, ASSET_ID, 1), Error::<Runtime>::Overflow);
+		assert_noop!(FT::mint(Origin::root(), BOB, ASSET_ID, 1), Error::<Runtime>::Overflow);
+		assert_eq!(FT::balance(ASSET_ID, ALICE), Balance::MAX);
+		assert_eq!(FT::total_supply(ASSET_ID), Balance::MAX);
+	});
+}
+
+#[test]
+fn ft_transfer_should_match_dispatchable() {
+	let genesis = vec![(ALICE, 100), (BOB, 10)];
+	let cases: Vec<(AccountId, AccountId, AssetId, Balance)> = vec![
+		(ALICE, BOB, ASSET_ID, 40),
+		(BOB, CHARLIE, ASSET_ID, 10),
+		(BOB, ALICE, ASSET_ID, 11),
+		(ALICE, BOB, ASSET_ID_NOT_EXIST, 1),
+	];
+	for (from, to, asset_id, amount) in cases {
+		let by_call = ExtBuilder::default().balances(genesis.clone()).build().execute_with(|| {
+			let result = FT::transfer(Origin::signed(from), to, asset_id, amount);
+			(result, FT::balance(ASSET_ID, from), FT::balance(ASSET_ID, to), FT::total_supply(ASSET_ID))
+		});
+		let by_trait = ExtBuilder::default().balances(genesis.clone()).build().execute_with(|| {
+			let result = <FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&from, &to, asset_id, amount);
+			(result, FT::balance(ASSET_ID, from), FT::balance(ASSET_ID, to), FT::total_supply(ASSET_ID))
+		});
+		assert_eq!(by_call, by_trait);
+	}
+}
+
+#[test]
+fn fungibles_inspect_should_match_storage() {
+	ExtBuilder::default().balances(vec![(ALICE, 100), (BOB, 50)]).build().execute_with(|| {
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::total_issuance(ASSET_ID), FT::total_supply(ASSET_ID));
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::total_issuance(ASSET_ID), 150);
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::balance(ASSET_ID, &ALICE), FT::balance(ASSET_ID, ALICE));
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::balance(ASSET_ID, &BOB), 50);
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::reducible_balance(ASSET_ID, &BOB, false), 50);
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::reducible_balance(ASSET_ID, &BOB, true), 49);
+	});
+}
+
+#[test]
+fn fungibles_can_deposit_and_withdraw_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_deposit(ASSET_ID, &BOB, 10, true),
+			DepositConsequence::Success
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_deposit(ASSET_ID_NOT_EXIST, &BOB, 10, true),
+			DepositConsequence::UnknownAsset
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_deposit(ASSET_ID, &ALICE, Balance::MAX, true),
+			DepositConsequence::Overflow
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_withdraw(ASSET_ID, &ALICE, 100),
+			WithdrawConsequence::Success
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_withdraw(ASSET_ID, &BOB, 1),
+			WithdrawConsequence::NoFunds
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_withdraw(ASSET_ID, &ALICE, 101),
+			WithdrawConsequence::Underflow
+		);
+	});
+}
+
+#[test]
+fn fungibles_mutate_should_track_total_supply() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(<FT as fungibles::Mutate<AccountId>>::mint_into(ASSET_ID, &BOB, 40));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 40);
+		assert_eq!(FT::total_supply(ASSET_ID), 140);
+
+		assert_eq!(<FT as fungibles::Mutate<AccountId>>::burn_from(ASSET_ID, &ALICE, 30), Ok(30));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 70);
+		assert_eq!(FT::total_supply(ASSET_ID), 110);
+
+		assert_noop!(
+			<FT as fungibles::Mutate<AccountId>>::burn_from(ASSET_ID, &BOB, 41),
+			Error::<Runtime>::InsufficientBalance
+		);
+		assert_noop!(
+			<FT as fungibles::Mutate<AccountId>>::mint_into(ASSET_ID_NOT_EXIST, &BOB, 1),
+			Error::<Runtime>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn fungibles_transfer_should_keep_total_supply() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_eq!(<FT as fungibles::Transfer<AccountId>>::transfer(ASSET_ID, &ALICE, &BOB, 60, false), Ok(60));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 40);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 60);
+		assert_eq!(FT::total_supply(ASSET_ID), 100);
+		assert_noop!(
+			<FT as fungibles::Transfer<AccountId>>::transfer(ASSET_ID, &ALICE, &BOB, 41, false),
+			Error::<Runtime>::InsufficientBalance
+		);
+		assert_noop!(
+			<FT as fungibles::Transfer<AccountId>>::transfer(ASSET_ID, &ALICE, &BOB, 40, true),
+			Error::<Runtime>::KeepAlive
+		);
+		assert_eq!(<FT as fungibles::Transfer<AccountId>>::transfer(ASSET_ID, &ALICE, &BOB, 39, true), Ok(39));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 1);
+	});
+}
+
+#[test]
+fn fungibles_unbalanced_should_write_storage() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(<FT as fungibles::Unbalanced<AccountId>>::set_balance(ASSET_ID, &BOB, 25));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 25);
+		// `Unbalanced` leaves the total supply to the caller.
+		assert_eq!(FT::total_supply(ASSET_ID), 100);
+		<FT as fungibles::Unbalanced<AccountId>>::set_total_issuance(ASSET_ID, 125);
+		assert_eq!(FT::total_supply(ASSET_ID), 125);
+		assert_noop!(
+			<FT as fungibles::Unbalanced<AccountId>>::set_balance(ASSET_ID_NOT_EXIST, &BOB, 25),
+			Error::<Runtime>::AssetNotFound
+		);
+	});
+}
+
+parameter_types! {
+	pub const TestAssetId: AssetId = ASSET_ID;
+}
+
+type TestToken = fungible::ItemOf<FT, TestAssetId, AccountId>;
+
+#[test]
+fn fungible_item_of_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_eq!(<TestToken as fungible::Inspect<AccountId>>::total_issuance(), 100);
+		assert_eq!(<TestToken as fungible::Inspect<AccountId>>::balance(&ALICE), 100);
+		assert_ok!(<TestToken as fungible::Mutate<AccountId>>::mint_into(&BOB, 10));
+		assert_eq!(<TestToken as fungible::Transfer<AccountId>>::transfer(&BOB, &CHARLIE, 4, false), Ok(4));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 6);
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 4);
+		assert_eq!(FT::total_supply(ASSET_ID), 110);
+	});
+}
+
+const RESERVE_ID: [u8; 8] = *b"reserve1";
+const OTHER_RESERVE_ID: [u8; 8] = *b"reserve2";
+
+#[test]
+fn reserve_and_unreserve_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 30));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 70);
+		assert_eq!(FT::reserved_balance(&RESERVE_ID, &ALICE, ASSET_ID), 30);
+		assert_eq!(FT::total_supply(ASSET_ID), 100);
+
+		assert_noop!(FT::do_unreserve(&RESERVE_ID, &ALICE, ASSET_ID, 31), Error::<Runtime>::InsufficientReserve);
+		assert_noop!(FT::do_unreserve(&OTHER_RESERVE_ID, &ALICE, ASSET_ID, 1), Error::<Runtime>::InsufficientReserve);
+
+		assert_ok!(FT::do_unreserve(&RESERVE_ID, &ALICE, ASSET_ID, 30));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 100);
+		assert_eq!(FT::reserves(ASSET_ID, ALICE).len(), 0);
+	});
+}
+
+#[test]
+fn reserve_should_respect_max_reserves() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 10));
+		assert_ok!(FT::do_reserve(&OTHER_RESERVE_ID, &ALICE, ASSET_ID, 10));
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 10));
+		assert_noop!(FT::do_reserve(b"reserve3", &ALICE, ASSET_ID, 10), Error::<Runtime>::TooManyReserves);
+		assert_eq!(FT::total_reserved_balance(&ALICE, ASSET_ID), 30);
+	});
+}
+
+#[test]
+fn repatriate_reserved_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 50));
+		assert_ok!(FT::do_repatriate_reserved(&RESERVE_ID, &ALICE, &BOB, ASSET_ID, 20, BalanceStatus::Free));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 20);
+		assert_ok!(FT::do_repatriate_reserved(&RESERVE_ID, &ALICE, &BOB, ASSET_ID, 20, BalanceStatus::Reserved));
+		assert_eq!(FT::reserved_balance(&RESERVE_ID, &BOB, ASSET_ID), 20);
+		assert_eq!(FT::reserved_balance(&RESERVE_ID, &ALICE, ASSET_ID), 10);
+		assert_eq!(FT::total_supply(ASSET_ID), 100);
+	});
+}
+
+#[test]
+fn freeze_should_block_spending() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::do_set_freeze(&RESERVE_ID, &ALICE, ASSET_ID, 60));
+		assert_ok!(FT::do_set_freeze(&OTHER_RESERVE_ID, &ALICE, ASSET_ID, 30));
+		assert_eq!(FT::frozen_balance(&ALICE, ASSET_ID), 60);
+		assert_noop!(
+			FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 41),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+		assert_noop!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 41), Error::<Runtime>::LiquidityRestrictions);
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 40));
+
+		assert_ok!(FT::do_thaw(&RESERVE_ID, &ALICE, ASSET_ID));
+		assert_eq!(FT::frozen_balance(&ALICE, ASSET_ID), 30);
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 30));
+	});
+}
+
+#[test]
+fn permit_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let payload = FT::permit_payload(&ALICE, &BOB, ASSET_ID, 50, None, 0, 10);
+		let signature = TestSignature(ALICE, payload);
+		assert_ok!(FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, None, 0, 10, signature.clone()));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 50);
+		assert_eq!(FT::permit_nonce(ALICE), 1);
+
+		assert_noop!(
+			FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, None, 0, 10, signature),
+			Error::<Runtime>::InvalidPermitNonce
+		);
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 50));
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 50);
+	});
+}
+
+#[test]
+fn permit_should_fail() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let payload = FT::permit_payload(&ALICE, &BOB, ASSET_ID, 50, None, 0, 10);
+		assert_noop!(
+			FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, None, 0, 10, TestSignature(BOB, payload.clone())),
+			Error::<Runtime>::InvalidPermitSignature
+		);
+		assert_noop!(
+			FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 60, None, 0, 10, TestSignature(ALICE, payload.clone())),
+			Error::<Runtime>::InvalidPermitSignature
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, None, 0, 10, TestSignature(ALICE, payload.clone())),
+			Error::<Runtime>::PermitExpired
+		);
+		System::set_block_number(1);
+		assert_noop!(
+			FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, Some(5), 0, 10, TestSignature(ALICE, payload)),
+			Error::<Runtime>::InvalidPermitSignature
+		);
+	});
+}
+
+#[test]
+fn permit_allowance_should_expire() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let payload = FT::permit_payload(&ALICE, &BOB, ASSET_ID, 50, Some(5), 0, 10);
+		assert_ok!(FT::permit(Origin::signed(CHARLIE), ALICE, BOB, ASSET_ID, 50, Some(5), 0, 10, TestSignature(ALICE, payload)));
+		assert_eq!(FT::allowance((ASSET_ID, ALICE, BOB)), Approval { amount: 50, expiry: Some(5), epoch: 0 });
+		System::set_block_number(6);
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 0);
+		assert_noop!(
+			FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 10),
+			Error::<Runtime>::ApprovalExpired
+		);
+	});
+}
+
+#[test]
+fn increase_and_decrease_allowance_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 10, Some(5)));
+		assert_ok!(FT::increase_allowance(Origin::signed(ALICE), BOB, ASSET_ID, 15));
+		assert_eq!(FT::allowance((ASSET_ID, ALICE, BOB)), Approval { amount: 25, expiry: Some(5), epoch: 0 });
+		assert_ok!(FT::decrease_allowance(Origin::signed(ALICE), BOB, ASSET_ID, 20));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 5);
+		assert_noop!(
+			FT::decrease_allowance(Origin::signed(ALICE), BOB, ASSET_ID, 6),
+			Error::<Runtime>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn expired_allowance_should_not_be_spent() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 50, Some(5)));
+		System::set_block_number(5);
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 10));
+		System::set_block_number(6);
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 0);
+		assert_noop!(
+			FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 10),
+			Error::<Runtime>::ApprovalExpired
+		);
+		assert_noop!(
+			FT::increase_allowance(Origin::signed(ALICE), BOB, ASSET_ID, 10),
+			Error::<Runtime>::ApprovalExpired
+		);
+	});
+}
+
+#[test]
+fn revoke_all_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 50, None));
+		assert_ok!(FT::approve(Origin::signed(ALICE), CHARLIE, ASSET_ID, 50, None));
+		assert_ok!(FT::approve(Origin::signed(BOB), CHARLIE, ASSET_ID, 50, None));
+		assert_ok!(FT::revoke_all(Origin::signed(ALICE), ASSET_ID, 2, None));
+		System::assert_last_event(mock::Event::FT(crate::Event::AllApprovalsRevoked(ASSET_ID, ALICE)));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 0);
+		assert_eq!(FT::allowance_of(&ALICE, &CHARLIE, ASSET_ID), 0);
+		assert_eq!(FT::allowance_of(&BOB, &CHARLIE, ASSET_ID), 50);
+	});
+}
+
+#[test]
+fn revoke_all_should_continue_from_cursor() {
+	let mut ext = ExtBuilder::default().balances(vec![(ALICE, 100)]).build();
+	ext.execute_with(|| {
+		for spender in [BOB, CHARLIE, EXEMPT] {
+			assert_ok!(FT::approve(Origin::signed(ALICE), spender, ASSET_ID, 50, None));
+		}
+	});
+	// `clear_prefix` only honours its limit for entries already in the backend.
+	ext.commit_all().unwrap();
+	ext.execute_with(|| {
+		assert_ok!(FT::revoke_all(Origin::signed(ALICE), ASSET_ID, 2, None));
+		let cursor = match System::events().last().map(|record| record.event.clone()) {
+			Some(mock::Event::FT(crate::Event::ApprovalsPartiallyRevoked(ASSET_ID, ALICE, cursor))) => cursor,
+			event => panic!("unexpected event {:?}", event),
+		};
+		let remaining = [BOB, CHARLIE, EXEMPT].iter().filter(|&&spender| FT::allowance_of(&ALICE, &spender, ASSET_ID) > 0).count();
+		assert_eq!(remaining, 1);
+
+		assert_ok!(FT::revoke_all(Origin::signed(ALICE), ASSET_ID, 2, Some(cursor)));
+		System::assert_last_event(mock::Event::FT(crate::Event::AllApprovalsRevoked(ASSET_ID, ALICE)));
+		for spender in [BOB, CHARLIE, EXEMPT] {
+			assert_eq!(FT::allowance_of(&ALICE, &spender, ASSET_ID), 0);
+		}
+	});
+}
+
+#[test]
+fn vested_transfer_should_lock_until_vest() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let schedule = VestingInfo { locked: 40, per_block: 10, start: 1, cliff: 3 };
+		assert_ok!(FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 40);
+		assert_eq!(FT::vesting_lock(ASSET_ID, BOB), 40);
+		assert_noop!(
+			FT::transfer(Origin::signed(BOB), CHARLIE, ASSET_ID, 1),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+		assert_noop!(
+			<FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&BOB, &CHARLIE, ASSET_ID, 1),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+
+		// Nothing unlocks before the cliff.
+		System::set_block_number(2);
+		assert_ok!(FT::vest(Origin::signed(BOB), ASSET_ID));
+		assert_eq!(FT::vesting_lock(ASSET_ID, BOB), 40);
+
+		System::set_block_number(3);
+		assert_ok!(FT::vest(Origin::signed(BOB), ASSET_ID));
+		assert_eq!(FT::vesting_lock(ASSET_ID, BOB), 20);
+		assert_ok!(FT::transfer(Origin::signed(BOB), CHARLIE, ASSET_ID, 20));
+		assert_noop!(
+			FT::transfer(Origin::signed(BOB), CHARLIE, ASSET_ID, 1),
+			Error::<Runtime>::LiquidityRestrictions
+		);
+
+		System::set_block_number(5);
+		assert_ok!(FT::vest(Origin::signed(BOB), ASSET_ID));
+		assert_eq!(FT::vesting_lock(ASSET_ID, BOB), 0);
+		assert_eq!(FT::vesting(ASSET_ID, BOB).len(), 0);
+		assert_ok!(FT::transfer(Origin::signed(BOB), CHARLIE, ASSET_ID, 20));
+		assert_noop!(FT::vest(Origin::signed(BOB), ASSET_ID), Error::<Runtime>::NotVesting);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let schedule = VestingInfo { locked: 10, per_block: 0, start: 1, cliff: 1 };
+		assert_noop!(
+			FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule),
+			Error::<Runtime>::InvalidVestingSchedule
+		);
+		let schedule = VestingInfo { locked: 9, per_block: 1, start: 1, cliff: 1 };
+		assert_noop!(
+			FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule),
+			Error::<Runtime>::AmountLow
+		);
+		let schedule = VestingInfo { locked: 10, per_block: 1, start: 1, cliff: 1 };
+		assert_ok!(FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule.clone()));
+		assert_ok!(FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule.clone()));
+		assert_noop!(
+			FT::vested_transfer(Origin::signed(ALICE), BOB, ASSET_ID, schedule),
+			Error::<Runtime>::TooManyVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn snapshot_should_record_balances() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 1), None);
+		assert_noop!(FT::snapshot(Origin::signed(ALICE), ASSET_ID), BadOrigin);
+
+		assert_ok!(FT::snapshot(Origin::root(), ASSET_ID));
+		System::assert_last_event(mock::Event::FT(crate::Event::SnapshotTaken(ASSET_ID, 1)));
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 30));
+		assert_ok!(FT::mint(Origin::root(), CHARLIE, ASSET_ID, 50));
+
+		assert_ok!(FT::snapshot(Origin::root(), ASSET_ID));
+		assert_ok!(FT::snapshot(Origin::root(), ASSET_ID));
+		assert_ok!(FT::transfer(Origin::signed(BOB), CHARLIE, ASSET_ID, 10));
+
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 1), Some(100));
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 1), Some(0));
+		assert_eq!(FT::total_supply_at(ASSET_ID, 1), Some(100));
+
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 2), Some(70));
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 2), Some(30));
+		assert_eq!(FT::balance_at(&CHARLIE, ASSET_ID, 2), Some(50));
+		assert_eq!(FT::total_supply_at(ASSET_ID, 2), Some(150));
+
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 3), Some(30));
+		assert_eq!(FT::balance_at(&CHARLIE, ASSET_ID, 3), Some(50));
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 4), None);
+		assert_eq!(FT::total_supply_at(ASSET_ID, 0), None);
+	});
+}
+
+#[test]
+fn snapshot_should_prune_oldest_checkpoint() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(FT::snapshot(Origin::root(), ASSET_ID));
+			assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10));
+		}
+		assert_eq!(AccountCheckpoints::<Runtime>::get(ASSET_ID, ALICE).0, 1);
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 1), None);
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 2), Some(90));
+		assert_eq!(FT::balance_at(&ALICE, ASSET_ID, 3), Some(80));
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 1), None);
+		assert_eq!(FT::balance_at(&BOB, ASSET_ID, 3), Some(20));
+		// The supply never changed, so its snapshots are all still known.
+		assert_eq!(FT::total_supply_at(ASSET_ID, 1), Some(100));
+	});
+}
+
+#[test]
+fn transfer_hooks_should_see_every_move() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10));
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 10, None));
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 10));
+		assert_ok!(FT::mint(Origin::root(), BOB, ASSET_ID, 5));
+		assert_ok!(FT::burn(Origin::root(), BOB, ASSET_ID, 5));
+		assert_ok!(<FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&BOB, &CHARLIE, ASSET_ID, 1));
+		assert_eq!(TransferLog::get(), vec![
+			(ASSET_ID, Some(ALICE), Some(BOB), 10),
+			(ASSET_ID, Some(ALICE), Some(CHARLIE), 10),
+			(ASSET_ID, None, Some(BOB), 5),
+			(ASSET_ID, Some(BOB), None, 5),
+			(ASSET_ID, Some(BOB), Some(CHARLIE), 1),
+		]);
+	});
+}
+
+#[test]
+fn transfer_filter_should_veto_moves() {
+	ExtBuilder::default().balances(vec![(ALICE, 100), (BLOCKED, 100)]).build().execute_with(|| {
+		let blocked = DispatchError::Other("Blocked");
+		assert_noop!(FT::transfer(Origin::signed(ALICE), BLOCKED, ASSET_ID, 10), blocked);
+		assert_noop!(FT::transfer(Origin::signed(BLOCKED), ALICE, ASSET_ID, 10), blocked);
+		assert_noop!(FT::mint(Origin::root(), BLOCKED, ASSET_ID, 10), blocked);
+		assert_noop!(FT::burn_own(Origin::signed(BLOCKED), ASSET_ID, 10), blocked);
+		assert_noop!(
+			<FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&ALICE, &BLOCKED, ASSET_ID, 10),
+			blocked
+		);
+		assert_eq!(TransferLog::get(), vec![]);
+	});
+}
+
+#[test]
+fn pause_should_stop_movements() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_noop!(FT::pause(Origin::signed(ALICE), None), BadOrigin);
+
+		assert_ok!(FT::pause(Origin::root(), Some(ASSET_ID)));
+		System::assert_last_event(mock::Event::FT(crate::Event::Paused(Some(ASSET_ID))));
+		assert_noop!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10), Error::<Runtime>::AssetPaused);
+		assert_noop!(FT::mint(Origin::root(), BOB, ASSET_ID, 10), Error::<Runtime>::AssetPaused);
+		assert_noop!(
+			<FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&ALICE, &BOB, ASSET_ID, 10),
+			Error::<Runtime>::AssetPaused
+		);
+		assert_noop!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 10), Error::<Runtime>::AssetPaused);
+		assert_ok!(FT::unpause(Origin::root(), Some(ASSET_ID)));
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10));
+
+		assert_ok!(FT::pause(Origin::root(), None));
+		assert_noop!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10), Error::<Runtime>::AssetPaused);
+		assert_ok!(FT::unpause(Origin::root(), None));
+		System::assert_last_event(mock::Event::FT(crate::Event::Unpaused(None)));
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10));
+	});
+}
+
+#[test]
+fn frozen_account_cannot_send() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::freeze_account(Origin::root(), ALICE, ASSET_ID));
+		System::assert_last_event(mock::Event::FT(crate::Event::AccountFrozen(ASSET_ID, ALICE)));
+		assert_noop!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10), Error::<Runtime>::AccountIsFrozen);
+		assert_noop!(
+			<FT as FTTransfer<AccountId, AssetId, Balance>>::transfer(&ALICE, &BOB, ASSET_ID, 10),
+			Error::<Runtime>::AccountIsFrozen
+		);
+		assert_eq!(<FT as fungibles::Inspect<AccountId>>::reducible_balance(ASSET_ID, &ALICE, false), 0);
+
+		// Frozen accounts can still receive.
+		assert_ok!(FT::mint(Origin::root(), ALICE, ASSET_ID, 10));
+
+		assert_ok!(FT::thaw_account(Origin::root(), ALICE, ASSET_ID));
+		System::assert_last_event(mock::Event::FT(crate::Event::AccountThawed(ASSET_ID, ALICE)));
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10));
+	});
+}
+
+#[test]
+fn transfer_should_charge_fee() {
+	ExtBuilder::default().balances(vec![(ALICE, 100_000)]).build().execute_with(|| {
+		assert_noop!(
+			FT::set_transfer_fee(Origin::root(), ASSET_ID, MAX_FEE_BPS + 1, TREASURY),
+			Error::<Runtime>::InvalidFeeRate
+		);
+		assert_ok!(FT::set_transfer_fee(Origin::root(), ASSET_ID, 250, TREASURY));
+		System::assert_last_event(mock::Event::FT(crate::Event::TransferFeeSet(ASSET_ID, 250, TREASURY)));
+
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10_000));
+		System::assert_last_event(mock::Event::FT(crate::Event::FeeCharged(ASSET_ID, ALICE, BOB, 10_000, 9_750, 250)));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 90_000);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 9_750);
+		assert_eq!(FT::balance(ASSET_ID, TREASURY), 250);
+
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 1_000, None));
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, CHARLIE, ASSET_ID, 1_000));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 0);
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 975);
+		assert_eq!(FT::balance(ASSET_ID, TREASURY), 275);
+
+		// Amounts too small to carry a fee move in full.
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 39));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 9_789);
+
+		// Exempt accounts pay no fee, and neither do transfers to them.
+		assert_ok!(FT::transfer(Origin::signed(ALICE), EXEMPT, ASSET_ID, 10_000));
+		assert_eq!(FT::balance(ASSET_ID, EXEMPT), 10_000);
+		assert_ok!(FT::transfer(Origin::signed(EXEMPT), BOB, ASSET_ID, 10_000));
+		assert_eq!(FT::balance(ASSET_ID, TREASURY), 275);
+
+		assert_ok!(FT::set_transfer_fee(Origin::root(), ASSET_ID, 0, TREASURY));
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 10_000));
+		assert_eq!(FT::balance(ASSET_ID, TREASURY), 275);
+	});
+}
+
+#[test]
+fn transfer_fee_should_be_checked_against_gross_amount() {
+	ExtBuilder::default().minimum_balance(10).balances(vec![(ALICE, 1_000)]).build().execute_with(|| {
+		assert_ok!(FT::set_transfer_fee(Origin::root(), ASSET_ID, 100, TREASURY));
+
+		// The fee leg would overdraw, so nothing moves.
+		assert_noop!(
+			FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 1_001),
+			Error::<Runtime>::InsufficientBalance
+		);
+
+		// A fee below the minimum balance still creates the recipient.
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 500));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 500);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 495);
+		assert_eq!(FT::balance(ASSET_ID, TREASURY), 5);
+		assert_eq!(FT::total_supply(ASSET_ID), 1_000);
+	});
+}
+
+#[test]
+fn wrap_and_unwrap_should_keep_supply_backed() {
+	let endowed = vec![(ALICE, 1_000), (BOB, 1_000), (FT::account_id(), 1)];
+	ExtBuilder::default().native_balances(endowed).build().execute_with(|| {
+		let native_issuance = NativeBalances::total_issuance();
+		let backed = || {
+			assert_eq!(FT::total_supply(WRAPPED_ASSET_ID) + 1, NativeBalances::free_balance(&FT::account_id()));
+			assert_eq!(NativeBalances::total_issuance(), native_issuance);
+		};
+
+		assert_ok!(FT::wrap(Origin::signed(ALICE), 300));
+		System::assert_last_event(mock::Event::FT(crate::Event::Wrapped(ALICE, 300)));
+		assert_eq!(NativeBalances::free_balance(&ALICE), 700);
+		assert_eq!(FT::balance(WRAPPED_ASSET_ID, ALICE), 300);
+		backed();
+
+		assert_ok!(FT::wrap(Origin::signed(BOB), 200));
+		assert_ok!(FT::transfer(Origin::signed(BOB), ALICE, WRAPPED_ASSET_ID, 50));
+		backed();
+
+		assert_ok!(FT::unwrap(Origin::signed(ALICE), 350));
+		System::assert_last_event(mock::Event::FT(crate::Event::Unwrapped(ALICE, 350)));
+		assert_eq!(NativeBalances::free_balance(&ALICE), 1_050);
+		assert_eq!(FT::balance(WRAPPED_ASSET_ID, ALICE), 0);
+		backed();
+
+		assert_noop!(FT::unwrap(Origin::signed(BOB), 151), Error::<Runtime>::InsufficientBalance);
+		assert_noop!(
+			FT::wrap(Origin::signed(BOB), 1_000),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+		assert_ok!(FT::unwrap(Origin::signed(BOB), 150));
+		assert_eq!(FT::total_supply(WRAPPED_ASSET_ID), 0);
+		backed();
+	});
+}
+
+#[test]
+fn native_asset_supply_should_stay_backed() {
+	let endowed = vec![(ALICE, 1_000), (BOB, 1_000), (FT::account_id(), 1)];
+	ExtBuilder::default().minimum_balance(10).native_balances(endowed).build().execute_with(|| {
+		let backed = || {
+			assert_eq!(FT::total_supply(WRAPPED_ASSET_ID) + 1, NativeBalances::free_balance(&FT::account_id()));
+		};
+		let restricted = Error::<Runtime>::NativeAssetRestricted;
+		assert_ok!(FT::wrap(Origin::signed(ALICE), 300));
+
+		assert_noop!(FT::mint(Origin::root(), BOB, WRAPPED_ASSET_ID, 100), restricted);
+		assert_noop!(FT::mint(Origin::signed(ALICE), BOB, WRAPPED_ASSET_ID, 100), restricted);
+		assert_noop!(FT::burn(Origin::root(), ALICE, WRAPPED_ASSET_ID, 100), restricted);
+		assert_noop!(FT::burn_own(Origin::signed(ALICE), WRAPPED_ASSET_ID, 100), restricted);
+		assert_noop!(<FT as fungibles::Mutate<AccountId>>::mint_into(WRAPPED_ASSET_ID, &BOB, 100), restricted);
+		assert_noop!(<FT as fungibles::Mutate<AccountId>>::burn_from(WRAPPED_ASSET_ID, &ALICE, 100), restricted);
+		assert_noop!(<FT as fungibles::Unbalanced<AccountId>>::set_balance(WRAPPED_ASSET_ID, &BOB, 100), restricted);
+		<FT as fungibles::Unbalanced<AccountId>>::set_total_issuance(WRAPPED_ASSET_ID, 0);
+		backed();
+
+		// Balances below the minimum are kept rather than burned.
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, WRAPPED_ASSET_ID, 295));
+		assert_eq!(FT::balance(WRAPPED_ASSET_ID, ALICE), 5);
+		backed();
+
+		assert_ok!(FT::unwrap(Origin::signed(BOB), 295));
+		assert_ok!(FT::unwrap(Origin::signed(ALICE), 5));
+		assert_eq!(FT::total_supply(WRAPPED_ASSET_ID), 0);
+		backed();
+	});
+}
+
+type FeeAdapter = <Runtime as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+#[test]
+fn transaction_fee_should_fall_back_to_token() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.native_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			let call = mock::Call::FT(crate::Call::burn_own { asset_id: ASSET_ID, amount: 1 });
+			let info = DispatchInfo::default();
+			let post_info = PostDispatchInfo::default();
+
+			// Without a conversion rate the token cannot pay fees.
+			assert_eq!(
+				FeeAdapter::withdraw_fee(&BOB, &call, &info, 100, 0).err(),
+				Some(InvalidTransaction::Payment.into())
+			);
+
+			assert_noop!(
+				FT::set_fee_conversion_rate(Origin::signed(ALICE), ASSET_ID, Some(FixedU128::saturating_from_integer(2))),
+				BadOrigin
+			);
+			assert_ok!(FT::set_fee_conversion_rate(Origin::root(), ASSET_ID, Some(FixedU128::saturating_from_integer(2))));
+
+			// Native currency is used when the account has enough of it.
+			let liquidity = FeeAdapter::withdraw_fee(&ALICE, &call, &info, 100, 0).unwrap();
+			assert_ok!(FeeAdapter::correct_and_deposit_fee(&ALICE, &info, &post_info, 100, 0, liquidity));
+			assert_eq!(NativeBalances::free_balance(&ALICE), 900);
+			assert_eq!(FT::balance(ASSET_ID, ALICE), 1_000);
+
+			// Otherwise the fee is taken in tokens and the unused part refunded.
+			let liquidity = FeeAdapter::withdraw_fee(&BOB, &call, &info, 100, 0).unwrap();
+			assert_eq!(FT::balance(ASSET_ID, BOB), 800);
+			assert_ok!(FeeAdapter::correct_and_deposit_fee(&BOB, &info, &post_info, 60, 0, liquidity));
+			System::assert_last_event(mock::Event::FT(crate::Event::TransactionFeePaid(ASSET_ID, BOB, 120, 80)));
+			assert_eq!(FT::balance(ASSET_ID, BOB), 880);
+			assert_eq!(FT::total_supply(ASSET_ID), 1_880);
+			assert_eq!(TransferLog::get(), vec![(ASSET_ID, Some(BOB), None, 120)]);
+
+			assert_eq!(
+				FeeAdapter::withdraw_fee(&BOB, &call, &info, 1_000, 0).err(),
+				Some(InvalidTransaction::Payment.into())
+			);
+		});
+}
+
+#[test]
+fn transaction_fee_should_keep_payer_alive() {
+	ExtBuilder::default().minimum_balance(10).balances(vec![(BOB, 210)]).build().execute_with(|| {
+		let call = mock::Call::FT(crate::Call::burn_own { asset_id: ASSET_ID, amount: 1 });
+		let info = DispatchInfo::default();
+		let post_info = PostDispatchInfo::default();
+		assert_ok!(FT::set_fee_conversion_rate(Origin::root(), ASSET_ID, Some(FixedU128::saturating_from_integer(2))));
+
+		// Paying 202 would leave 8, below the minimum balance.
+		assert_eq!(
+			FeeAdapter::withdraw_fee(&BOB, &call, &info, 101, 0).err(),
+			Some(InvalidTransaction::Payment.into())
+		);
+
+		let liquidity = FeeAdapter::withdraw_fee(&BOB, &call, &info, 100, 0).unwrap();
+		assert_eq!(FT::balance(ASSET_ID, BOB), 10);
+		assert_ok!(FeeAdapter::correct_and_deposit_fee(&BOB, &info, &post_info, 50, 0, liquidity));
+		assert_eq!(FT::balance(ASSET_ID, BOB), 110);
+		assert_eq!(FT::total_supply(ASSET_ID), 110);
+	});
+}
+
+#[test]
+fn holder_count_should_track_accounts() {
+	ExtBuilder::default().balances(vec![(ALICE, 100), (BOB, 50)]).build().execute_with(|| {
+		assert_eq!(FT::holder_count(ASSET_ID), 2);
+		assert_ok!(FT::transfer(Origin::signed(ALICE), CHARLIE, ASSET_ID, 10));
+		assert_eq!(FT::holder_count(ASSET_ID), 3);
+
+		// Emptied accounts are removed from storage.
+		assert_ok!(FT::transfer(Origin::signed(BOB), ALICE, ASSET_ID, 50));
+		assert_eq!(FT::holder_count(ASSET_ID), 2);
+		assert!(!Balances::<Runtime>::contains_key(ASSET_ID, BOB));
+
+		// Accounts with reserved tokens are kept.
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &CHARLIE, ASSET_ID, 10));
+		assert!(Balances::<Runtime>::contains_key(ASSET_ID, CHARLIE));
+		assert_eq!(FT::holder_count(ASSET_ID), 2);
+		assert_ok!(FT::do_repatriate_reserved(&RESERVE_ID, &CHARLIE, &ALICE, ASSET_ID, 10, BalanceStatus::Free));
+		assert!(!Balances::<Runtime>::contains_key(ASSET_ID, CHARLIE));
+		assert_eq!(FT::holder_count(ASSET_ID), 1);
+	});
+}
+
+#[test]
+fn repatriating_to_reserve_should_count_holder() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::do_reserve(&RESERVE_ID, &ALICE, ASSET_ID, 50));
+		assert_ok!(FT::do_repatriate_reserved(&RESERVE_ID, &ALICE, &BOB, ASSET_ID, 20, BalanceStatus::Reserved));
+		assert!(Balances::<Runtime>::contains_key(ASSET_ID, BOB));
+		assert_eq!(FT::holder_count(ASSET_ID), 2);
+
+		// Once the reserve is released and spent, the account is reaped like any other.
+		assert_ok!(FT::do_unreserve(&RESERVE_ID, &BOB, ASSET_ID, 20));
+		assert_ok!(FT::transfer(Origin::signed(BOB), ALICE, ASSET_ID, 20));
+		assert!(!Balances::<Runtime>::contains_key(ASSET_ID, BOB));
+		assert_eq!(FT::holder_count(ASSET_ID), 1);
+	});
+}
+
+#[test]
+fn dust_should_be_burned() {
+	ExtBuilder::default().minimum_balance(10).balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_noop!(
+			FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 9),
+			Error::<Runtime>::BelowMinimumBalance
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_deposit(ASSET_ID, &BOB, 9, false),
+			DepositConsequence::BelowMinimum
+		);
+		assert_eq!(
+			<FT as fungibles::Inspect<AccountId>>::can_withdraw(ASSET_ID, &ALICE, 95),
+			WithdrawConsequence::ReducedToZero(5)
+		);
+
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 95));
+		System::assert_last_event(mock::Event::FT(crate::Event::DustLost(ASSET_ID, ALICE, 5)));
+		assert_eq!(TransferLog::get(), vec![
+			(ASSET_ID, Some(ALICE), Some(BOB), 95),
+			(ASSET_ID, Some(ALICE), None, 5),
+		]);
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 0);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 95);
+		assert_eq!(FT::total_supply(ASSET_ID), 95);
+		assert_eq!(FT::holder_count(ASSET_ID), 1);
+	});
+}
+
+#[test]
+fn reaping_should_clear_allowances() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 50, None));
+		assert_ok!(FT::approve(Origin::signed(ALICE), CHARLIE, ASSET_ID, 50, None));
+		assert_ok!(FT::transfer_from(Origin::signed(BOB), ALICE, BOB, ASSET_ID, 50));
+		assert_eq!(FT::allowance_of(&ALICE, &CHARLIE, ASSET_ID), 50);
+
+		assert_ok!(FT::transfer(Origin::signed(ALICE), BOB, ASSET_ID, 50));
+		assert_eq!(FT::allowance_of(&ALICE, &CHARLIE, ASSET_ID), 0);
+		assert_eq!(FT::allowance_epoch(ASSET_ID, ALICE), 1);
+
+		assert_noop!(
+			FT::transfer_from(Origin::signed(CHARLIE), ALICE, CHARLIE, ASSET_ID, 1),
+			Error::<Runtime>::InsufficientAllowance
+		);
+
+		// The stale allowance is not revived by later changes.
+		assert_ok!(FT::increase_allowance(Origin::signed(ALICE), CHARLIE, ASSET_ID, 5));
+		assert_eq!(FT::allowance_of(&ALICE, &CHARLIE, ASSET_ID), 5);
+	});
+}
+
+#[test]
+fn batch_transfer_should_work() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let transfers: BatchTransfersOf<Runtime> = vec![(BOB, 10), (CHARLIE, 20), (BOB, 5)].try_into().unwrap();
+		assert_ok!(FT::batch_transfer(Origin::signed(ALICE), ASSET_ID, transfers));
+		System::assert_has_event(mock::Event::FT(crate::Event::Transferred(ASSET_ID, ALICE, CHARLIE, 20)));
+		System::assert_last_event(mock::Event::FT(crate::Event::BatchTransferred(ASSET_ID, ALICE, 3, 35)));
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 65);
+		assert_eq!(FT::balance(ASSET_ID, BOB), 15);
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 20);
+	});
+}
+
+#[test]
+fn batch_transfer_should_be_all_or_nothing() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let transfers: BatchTransfersOf<Runtime> = vec![(BOB, 60), (CHARLIE, 50)].try_into().unwrap();
+		assert_noop!(
+			FT::batch_transfer(Origin::signed(ALICE), ASSET_ID, transfers),
+			Error::<Runtime>::InsufficientBalance
+		);
+		let transfers: BatchTransfersOf<Runtime> = vec![(BOB, 60), (BLOCKED, 10)].try_into().unwrap();
+		assert_noop!(
+			FT::batch_transfer(Origin::signed(ALICE), ASSET_ID, transfers),
+			DispatchError::Other("Blocked")
+		);
+		let too_many: Result<BatchTransfersOf<Runtime>, _> = vec![(BOB, 1); 4].try_into();
+		assert!(too_many.is_err());
+	});
+}
+
+#[test]
+fn batch_transfer_from_should_spend_allowance() {
+	ExtBuilder::default().balances(vec![(ALICE, 100)]).build().execute_with(|| {
+		let transfers: BatchTransfersOf<Runtime> = vec![(BOB, 10), (CHARLIE, 20)].try_into().unwrap();
+		assert_ok!(FT::approve(Origin::signed(ALICE), BOB, ASSET_ID, 29, None));
+		assert_noop!(
+			FT::batch_transfer_from(Origin::signed(BOB), ALICE, ASSET_ID, transfers.clone()),
+			Error::<Runtime>::InsufficientAllowance
+		);
+		assert_ok!(FT::increase_allowance(Origin::signed(ALICE), BOB, ASSET_ID, 1));
+		assert_ok!(FT::batch_transfer_from(Origin::signed(BOB), ALICE, ASSET_ID, transfers));
+		System::assert_last_event(mock::Event::FT(crate::Event::BatchTransferred(ASSET_ID, ALICE, 2, 30)));
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 0);
+		assert_eq!(FT::balance(ASSET_ID, ALICE), 70);
+		assert_eq!(FT::balance(ASSET_ID, CHARLIE), 20);
+	});
+}
+
+#[test]
+fn migration_v1_should_move_legacy_token_under_default_asset() {
+	use frame_support::{storage::{storage_prefix, unhashed}, StorageHasher, Twox64Concat};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let old_key = |item: &[u8], suffix: &[u8]| [&storage_prefix(b"FT", item)[..], suffix].concat();
+		let alice = Twox64Concat::hash(&ALICE.encode());
+		let bob = Twox64Concat::hash(&BOB.encode());
+		unhashed::put(&old_key(b"Balances", &alice), &100u64);
+		unhashed::put(&old_key(b"Balances", &bob), &(u64::MAX - 100));
+		unhashed::put(&old_key(b"TotalSupply", &[]), &u64::MAX);
+		unhashed::put(&old_key(b"Allowances", &[alice, bob].concat()), &30u64);
+		StorageVersion::new(0).put::<FT>();
+
+		migrations::v1::migrate::<Runtime>();
+
+		assert_eq!(FT::balance(ASSET_ID, &ALICE), 100);
+		assert_eq!(FT::balance(ASSET_ID, &BOB), (u64::MAX - 100) as Balance);
+		assert_eq!(FT::holder_count(ASSET_ID), 2);
+		assert_eq!(FT::total_supply(ASSET_ID), u64::MAX as Balance);
+		assert_eq!(FT::allowance_of(&ALICE, &BOB, ASSET_ID), 30);
+		assert_eq!(FT::assets(ASSET_ID).unwrap().owner, FT::account_id());
+		assert!(!unhashed::exists(&storage_prefix(b"FT", b"TotalSupply")));
+		assert_eq!(StorageVersion::get::<FT>(), 1);
+	});
+}
@@ -0,0 +1,347 @@
+//! Weights for pallet_ft
+//!
+//! The values below are sized from the storage accesses of each benchmark in `benchmarking.rs`
+//! and have not been measured. Regenerate them on reference hardware with:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --pallet pallet_ft
+//! --extrinsic '*' --steps 50 --repeat 20 --execution wasm --wasm-execution compiled
+//! --output ./pallets/ft/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ft.
+pub trait WeightInfo {
+	fn create_asset() -> Weight;
+	fn transfer() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn batch_transfer(n: u32, ) -> Weight;
+	fn batch_transfer_from(n: u32, ) -> Weight;
+	fn revoke_all(n: u32, ) -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn burn_own() -> Weight;
+	fn set_metadata() -> Weight;
+}
+
+/// Weights for pallet_ft using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: FT NextAssetId (r:1 w:1)
+	// Storage: FT Assets (r:0 w:1)
+	fn create_asset() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn transfer() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:0 w:1)
+	fn approve() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn transfer_from() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn batch_transfer(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn batch_transfer_from(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	fn revoke_all(n: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn mint() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	fn burn() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	fn burn_own() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Metadata (r:0 w:1)
+	fn set_metadata() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: FT NextAssetId (r:1 w:1)
+	// Storage: FT Assets (r:0 w:1)
+	fn create_asset() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn transfer() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:0 w:1)
+	fn approve() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn transfer_from() -> Weight {
+		(55_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn batch_transfer(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT AllowanceEpochs (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT FeeRecipient (r:1 w:0)
+	// Storage: FT TransferFeeRate (r:1 w:0)
+	// Storage: FT Balances (r:3 w:3)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:3 w:3)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn batch_transfer_from(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Allowances (r:1 w:1)
+	fn revoke_all(n: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT HolderCount (r:1 w:1)
+	fn mint() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	fn burn() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Paused (r:1 w:0)
+	// Storage: FT PausedAssets (r:1 w:0)
+	// Storage: FT FrozenAccounts (r:1 w:0)
+	// Storage: FT Balances (r:1 w:1)
+	// Storage: FT Freezes (r:1 w:0)
+	// Storage: FT VestingLocks (r:1 w:0)
+	// Storage: FT CurrentSnapshotId (r:1 w:0)
+	// Storage: FT AccountCheckpoints (r:1 w:1)
+	// Storage: FT TotalSupply (r:1 w:1)
+	// Storage: FT SupplyCheckpoints (r:1 w:1)
+	fn burn_own() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: FT Assets (r:1 w:0)
+	// Storage: FT Metadata (r:0 w:1)
+	fn set_metadata() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
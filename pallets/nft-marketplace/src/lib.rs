@@ -1,44 +1,57 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{pallet_prelude::*, traits::UnixTime, PalletId};
+use frame_support::{pallet_prelude::*, traits::{tokens::BalanceStatus, UnixTime}, PalletId};
 //use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
 use codec::{Decode, Encode, MaxEncodedLen};
 use sp_runtime::{RuntimeDebug, traits::{AtLeast32BitUnsigned, AccountIdConversion}};
-use pallet_traits::{FTTransfer, NFTForMarketplace};
+use pallet_traits::{FTReserve, FTTransfer, NFTForMarketplace};
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
 
+pub mod migrations;
+
+#[cfg(test)]
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_system::ensure_signed;
-	use frame_system::pallet_prelude::OriginFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub struct SellItem<AccountId> {
+	pub struct SellItem<AccountId, AssetId, Balance> {
 		pub seller: AccountId,
-		pub current_price: u64,
+		pub asset_id: AssetId,
+		pub current_price: Balance,
 		pub current_winner: AccountId,
 		pub start_time: u64,
 		pub end_time: u64,
 	}
 
 	#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub struct RentItem<AccountId> {
+	pub struct RentItem<AccountId, AssetId, Balance> {
 		pub leaser: AccountId,
 		pub renter: AccountId,
-		pub collateral: u64,
-		pub price: u64,
+		pub asset_id: AssetId,
+		pub collateral: Balance,
+		pub price: Balance,
 		pub start_time: u64,
 		pub duration: u64,
 		pub is_renting: bool,
 	}
 
-	pub type SellItemOf<T> = SellItem<<T as frame_system::Config>::AccountId>;
-	pub type RentItemOf<T> = RentItem<<T as frame_system::Config>::AccountId>;
+	pub type SellItemOf<T> = SellItem<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, <T as Config>::Balance>;
+	pub type RentItemOf<T> = RentItem<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, <T as Config>::Balance>;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
@@ -53,22 +66,22 @@ pub mod pallet {
 		type MinBidDuration: Get<u64>;
 
 		#[pallet::constant]
-		type MinBasePrice: Get<u64>;
+		type MinBasePrice: Get<Self::Balance>;
 
 		#[pallet::constant]
-		type MaxBasePrice: Get<u64>;
+		type MaxBasePrice: Get<Self::Balance>;
 
 		#[pallet::constant]
-		type MinRentPrice: Get<u64>;
+		type MinRentPrice: Get<Self::Balance>;
 
 		#[pallet::constant]
-		type MaxRentPrice: Get<u64>;
+		type MaxRentPrice: Get<Self::Balance>;
 
 		#[pallet::constant]
-		type MinCollateral: Get<u64>;
+		type MinCollateral: Get<Self::Balance>;
 
 		#[pallet::constant]
-		type MaxCollateral: Get<u64>;
+		type MaxCollateral: Get<Self::Balance>;
 
 		#[pallet::constant]
 		type MinRentDuration: Get<u64>;
@@ -79,19 +92,27 @@ pub mod pallet {
 		type ClassId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
 		/// The token ID type
 		type TokenId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+		/// The fungible asset ID type
+		type AssetId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+		/// The fungible balance type
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
 
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
 		type NFT: NFTForMarketplace<Self::AccountId, Self::ClassId, Self::TokenId>;
 
-		type FT: FTTransfer<Self::AccountId>;
+		type FT: FTReserve<Self::AccountId, Self::AssetId, Self::Balance>;
+
+		/// The named reserve holding rental collateral on the renter's account
+		type RentReserveId: Get<<Self::FT as FTReserve<Self::AccountId, Self::AssetId, Self::Balance>>::ReserveIdentifier>;
 
 		type UnixTime: UnixTime;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -110,8 +131,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
-		Opened(T::AccountId, T::ClassId, T::TokenId, u64),
-		Bid(T::AccountId, T::ClassId, T::TokenId, u64),
+		Opened(T::AccountId, T::ClassId, T::TokenId, T::Balance),
+		Bid(T::AccountId, T::ClassId, T::TokenId, T::Balance),
 		Canceled(T::AccountId, T::ClassId, T::TokenId),
 		Closed(T::AccountId, T::ClassId, T::TokenId),
 
@@ -153,6 +174,15 @@ pub mod pallet {
 		OnlyOwnerCanCancelRent,
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+				.saturating_add(migrations::v2::migrate::<T>())
+				.saturating_add(migrations::v3::migrate::<T>())
+		}
+	}
+
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -163,7 +193,8 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			class_id: T::ClassId,
 			token_id: T::TokenId,
-			base_price: u64,
+			asset_id: T::AssetId,
+			base_price: T::Balance,
 			delay_duration: u64,
 			bid_duration: u64,
 		) -> DispatchResult {
@@ -172,6 +203,7 @@ pub mod pallet {
 				&seller,
 				class_id,
 				token_id,
+				asset_id,
 				base_price,
 				delay_duration,
 				bid_duration,
@@ -193,7 +225,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			class_id: T::ClassId,
 			token_id: T::TokenId,
-			bid_price: u64,
+			bid_price: T::Balance,
 		) -> DispatchResult {
 			let bidder = ensure_signed(origin)?;
 			Self::do_bid(
@@ -219,12 +251,13 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			class_id: T::ClassId,
 			token_id: T::TokenId,
+			asset_id: T::AssetId,
 			duration: u64,
-			collateral: u64,
-			price: u64,
+			collateral: T::Balance,
+			price: T::Balance,
 		) -> DispatchResult {
 			let leaser = ensure_signed(origin)?;
-			Self::do_offer(&leaser, class_id, token_id, duration, collateral, price)
+			Self::do_offer(&leaser, class_id, token_id, asset_id, duration, collateral, price)
 		}
 
 		#[pallet::weight(100_000)]
@@ -270,14 +303,15 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-	fn pallet_account_id() -> T::AccountId {
+	pub(crate) fn pallet_account_id() -> T::AccountId {
 		T::PalletId::get().into_account_truncating()
 	}
 	pub fn do_open(
 		seller: &T::AccountId,
 		class_id: T::ClassId,
 		token_id: T::TokenId,
-		base_price: u64,
+		asset_id: T::AssetId,
+		base_price: T::Balance,
 		delay_duration: u64,
 		bid_duration: u64,
 	) -> DispatchResult {
@@ -298,9 +332,10 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InvalidBidTime
 		);
 		ensure!(T::NFT::is_owner_of(seller, class_id, token_id), Error::<T>::OnlyOwnerCanSellNFT);
-		T::NFT::transfer(seller, &Self::pallet_account_id(), class_id, token_id)?;
+		T::NFT::lock(seller, &Self::pallet_account_id(), class_id, token_id)?;
 		let sell_item: SellItemOf<T> = SellItem {
 			seller: seller.clone(),
+			asset_id,
 			current_price: base_price,
 			current_winner: seller.clone(),
 			start_time,
@@ -319,7 +354,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(sell_item) = Self::sell_item(class_id, token_id) {
 			ensure!(*seller == sell_item.seller, Error::<T>::OnlyOwnerCanCancel);
 			ensure!(*seller == sell_item.current_winner, Error::<T>::CannotCancel);
-			T::NFT::transfer(&Self::pallet_account_id(), seller, class_id, token_id)?;
+			T::NFT::unlock(&Self::pallet_account_id(), class_id, token_id)?;
 			SellItems::<T>::remove(class_id, token_id);
 		} else {
 			return Err(Error::<T>::SellItemNotExist.into());
@@ -332,7 +367,7 @@ impl<T: Config> Pallet<T> {
 		bidder: &T::AccountId,
 		class_id: T::ClassId,
 		token_id: T::TokenId,
-		bid_price: u64,
+		bid_price: T::Balance,
 	) -> DispatchResult {
 		SellItems::<T>::try_mutate(class_id, token_id, |opt| -> DispatchResult{
 			let mut sell_item = opt.as_mut().ok_or(Error::<T>::SellItemNotExist)?;
@@ -340,9 +375,9 @@ impl<T: Config> Pallet<T> {
 			let now = T::UnixTime::now().as_secs();
 			ensure!((sell_item.start_time <= now && sell_item.end_time >= now), Error::<T>::NotInBidDuration);
 			ensure!(bid_price > sell_item.current_price, Error::<T>::TooLowBidPrice);
-			T::FT::transfer(bidder, &sell_item.seller, bid_price - sell_item.current_price)?;
+			T::FT::transfer(bidder, &sell_item.seller, sell_item.asset_id, bid_price - sell_item.current_price)?;
 			if *bidder != sell_item.current_winner {
-				T::FT::transfer(bidder, &sell_item.current_winner, sell_item.current_price)?;
+				T::FT::transfer(bidder, &sell_item.current_winner, sell_item.asset_id, sell_item.current_price)?;
 			}
 			sell_item.current_winner = bidder.clone();
 			sell_item.current_price = bid_price;
@@ -361,7 +396,7 @@ impl<T: Config> Pallet<T> {
 			ensure!(*winner == sell_item.current_winner, Error::<T>::OnlyWinnerCanClaim);
 			let now = T::UnixTime::now().as_secs();
 			ensure!(now > sell_item.end_time, Error::<T>::SellIsNotEnded);
-			T::NFT::transfer(&Self::pallet_account_id(), winner, class_id, token_id)?;
+			T::NFT::transfer_locked(&Self::pallet_account_id(), winner, class_id, token_id)?;
 			SellItems::<T>::remove(class_id, token_id);
 		} else {
 			return Err(Error::<T>::SellItemNotExist.into());
@@ -377,9 +412,10 @@ impl<T: Config> Pallet<T> {
 		leaser: &T::AccountId,
 		class_id: T::ClassId,
 		token_id: T::TokenId,
+		asset_id: T::AssetId,
 		duration: u64,
-		collateral: u64,
-		price: u64,
+		collateral: T::Balance,
+		price: T::Balance,
 	) -> DispatchResult {
 		ensure!(
 			(price >= T::MinRentPrice::get() && price <= T::MaxRentPrice::get()), Error::<T>::InvalidRentPrice
@@ -393,10 +429,11 @@ impl<T: Config> Pallet<T> {
 		ensure!(
 			T::NFT::is_owner_of(leaser, class_id, token_id), Error::<T>::OnlyOwnerCanOffer
 		);
-		T::NFT::transfer(leaser, &Self::pallet_account_id(), class_id, token_id)?;
+		T::NFT::lock(leaser, &Self::pallet_account_id(), class_id, token_id)?;
 		let rent_item: RentItemOf<T> = RentItem {
 			leaser: leaser.clone(),
 			renter: leaser.clone(),
+			asset_id,
 			collateral,
 			price,
 			start_time: 0,
@@ -416,7 +453,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(rent_item) = Self::rent_item(class_id, token_id) {
 			ensure!(!rent_item.is_renting, Error::<T>::ItemIsRenting);
 			ensure!(rent_item.leaser == *leaser, Error::<T>::OnlyOwnerCanCancelRent);
-			T::NFT::transfer(&Self::pallet_account_id(), leaser, class_id, token_id)?;
+			T::NFT::unlock(&Self::pallet_account_id(), class_id, token_id)?;
 			RentItems::<T>::remove(class_id, token_id);
 			Self::deposit_event(Event::<T>::RentCanceled(leaser.clone(), class_id, token_id));
 		} else {
@@ -434,9 +471,9 @@ impl<T: Config> Pallet<T> {
 			let rent_item = opt.as_mut().ok_or(Error::<T>::RentItemNotExist)?;
 			ensure!(!rent_item.is_renting, Error::<T>::ItemIsRenting);
 			ensure!(rent_item.leaser != *renter, Error::<T>::RenterMustNotBeLeaser);
-			T::FT::transfer(renter, &Self::pallet_account_id(), rent_item.collateral)?;
-			T::FT::transfer(renter, &rent_item.leaser, rent_item.price)?;
-			T::NFT::transfer(&Self::pallet_account_id(), renter, class_id, token_id)?;
+			T::FT::reserve(&T::RentReserveId::get(), renter, rent_item.asset_id, rent_item.collateral)?;
+			T::FT::transfer(renter, &rent_item.leaser, rent_item.asset_id, rent_item.price)?;
+			T::NFT::transfer_locked(&Self::pallet_account_id(), renter, class_id, token_id)?;
 			rent_item.renter = renter.clone();
 			rent_item.start_time = T::UnixTime::now().as_secs();
 			rent_item.is_renting = true;
@@ -455,7 +492,7 @@ impl<T: Config> Pallet<T> {
 			ensure!(rent_item.renter == *renter, Error::<T>::OnlyRenterCanRepay);
 			ensure!(rent_item.start_time + rent_item.duration >= T::UnixTime::now().as_secs(), Error::<T>::RentIsExpired);
 			T::NFT::transfer(renter, &rent_item.leaser, class_id, token_id)?;
-			T::FT::transfer(&Self::pallet_account_id(), renter, rent_item.collateral)?;
+			T::FT::unreserve(&T::RentReserveId::get(), renter, rent_item.asset_id, rent_item.collateral)?;
 			RentItems::<T>::remove(class_id, token_id);
 		} else {
 			return Err(Error::<T>::RentItemNotExist.into());
@@ -473,7 +510,14 @@ impl<T: Config> Pallet<T> {
 			ensure!(rent_item.is_renting, Error::<T>::ItemIsNotRenting);
 			ensure!(rent_item.leaser == *leaser, Error::<T>::OnlyLeaserCanLiquidate);
 			ensure!(rent_item.start_time + rent_item.duration < T::UnixTime::now().as_secs(), Error::<T>::RentIsNotExpired);
-			T::FT::transfer(&Self::pallet_account_id(), leaser, rent_item.collateral)?;
+			T::FT::repatriate_reserved(
+				&T::RentReserveId::get(),
+				&rent_item.renter,
+				leaser,
+				rent_item.asset_id,
+				rent_item.collateral,
+				BalanceStatus::Free,
+			)?;
 			RentItems::<T>::remove(class_id, token_id);
 			Self::deposit_event(Event::<T>::Liquidated(leaser.clone(), class_id, token_id, rent_item.renter));
 		} else {
@@ -0,0 +1,178 @@
+//! Storage migrations of the marketplace pallet.
+
+use super::*;
+
+/// Version 1 names the fungible asset of every listing and widens its amounts to `Balance`.
+pub mod v1 {
+	use super::*;
+	use frame_support::log;
+	use sp_runtime::SaturatedConversion;
+
+	/// Balance type of version 0.
+	pub type OldBalance = u64;
+
+	/// Auction of version 0.
+	#[derive(Decode)]
+	pub struct OldSellItem<AccountId> {
+		pub seller: AccountId,
+		pub current_price: OldBalance,
+		pub current_winner: AccountId,
+		pub start_time: u64,
+		pub end_time: u64,
+	}
+
+	/// Rental of version 0.
+	#[derive(Decode)]
+	pub struct OldRentItem<AccountId> {
+		pub leaser: AccountId,
+		pub renter: AccountId,
+		pub collateral: OldBalance,
+		pub price: OldBalance,
+		pub start_time: u64,
+		pub duration: u64,
+		pub is_renting: bool,
+	}
+
+	/// Prices and collaterals of version 0 are in the single token of the fungible token pallet,
+	/// which became the asset `AssetId::default()`.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let asset_id = T::AssetId::default();
+		let mut translated: Weight = 0;
+		SellItems::<T>::translate::<OldSellItem<T::AccountId>, _>(|_, _, old| {
+			translated += 1;
+			Some(SellItem {
+				seller: old.seller,
+				asset_id,
+				current_price: old.current_price.saturated_into(),
+				current_winner: old.current_winner,
+				start_time: old.start_time,
+				end_time: old.end_time,
+			})
+		});
+		RentItems::<T>::translate::<OldRentItem<T::AccountId>, _>(|_, _, old| {
+			translated += 1;
+			Some(RentItem {
+				leaser: old.leaser,
+				renter: old.renter,
+				asset_id,
+				collateral: old.collateral.saturated_into(),
+				price: old.price.saturated_into(),
+				start_time: old.start_time,
+				duration: old.duration,
+				is_renting: old.is_renting,
+			})
+		});
+		log::info!(target: "runtime::nft-marketplace", "migrated {} listings to v1", translated);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Version 2 holds the collateral of a rental as a named reserve on the renter's account instead
+/// of in the pallet account.
+pub mod v2 {
+	use super::*;
+	use frame_support::{log, transactional};
+
+	/// Moves the collateral of every ongoing rental from the pallet account back to its renter and
+	/// reserves it under `RentReserveId`, so that `do_repay` and `do_liquidate` can settle it.
+	///
+	/// Runs after `v1`, and the balances of `T::FT` must already be in their multi-asset layout.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for (class_id, token_id, rent_item) in RentItems::<T>::iter() {
+			reads += 1;
+			if !rent_item.is_renting {
+				continue;
+			}
+			if let Err(err) = restore_collateral::<T>(&rent_item) {
+				log::warn!(
+					target: "runtime::nft-marketplace",
+					"failed to move the collateral of rental {:?}/{:?} to its renter: {:?}",
+					class_id,
+					token_id,
+					err,
+				);
+			}
+			// The transfer and the reserve each read and write both balances of the renter.
+			reads += 4;
+			writes += 4;
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[transactional]
+	fn restore_collateral<T: Config>(rent_item: &RentItemOf<T>) -> DispatchResult {
+		T::FT::transfer(&Pallet::<T>::pallet_account_id(), &rent_item.renter, rent_item.asset_id, rent_item.collateral)?;
+		T::FT::reserve(&T::RentReserveId::get(), &rent_item.renter, rent_item.asset_id, rent_item.collateral)
+	}
+}
+
+/// Version 3 leaves listed and offered NFTs with their owners, locked by the pallet account,
+/// instead of holding them in the pallet account.
+pub mod v3 {
+	use super::*;
+	use frame_support::{log, transactional};
+
+	/// Moves every NFT still held by the pallet account for an auction or a rental offer back to
+	/// its owner and locks it, so that `do_cancel`, `do_claim`, `do_cancel_rent` and `do_rent` can
+	/// release it.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 3 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let pallet_account = Pallet::<T>::pallet_account_id();
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		let sellers = SellItems::<T>::iter().map(|(class_id, token_id, sell_item)| (class_id, token_id, sell_item.seller));
+		let leasers = RentItems::<T>::iter()
+			.filter(|(_, _, rent_item)| !rent_item.is_renting)
+			.map(|(class_id, token_id, rent_item)| (class_id, token_id, rent_item.leaser));
+		for (class_id, token_id, owner) in sellers.chain(leasers) {
+			reads += 3;
+			if !T::NFT::is_owner_of(&pallet_account, class_id, token_id) || T::NFT::is_locked(class_id, token_id) {
+				continue;
+			}
+			if let Err(err) = restore_nft::<T>(&pallet_account, &owner, class_id, token_id) {
+				log::warn!(
+					target: "runtime::nft-marketplace",
+					"failed to return NFT {:?}/{:?} to its owner: {:?}",
+					class_id,
+					token_id,
+					err,
+				);
+			}
+			// The transfer and the lock read the token and class and write the token, both owner
+			// entries, the approval and the lock.
+			reads += 3;
+			writes += 5;
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[transactional]
+	fn restore_nft<T: Config>(
+		pallet_account: &T::AccountId,
+		owner: &T::AccountId,
+		class_id: T::ClassId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		T::NFT::transfer(pallet_account, owner, class_id, token_id)?;
+		T::NFT::lock(owner, pallet_account, class_id, token_id)
+	}
+}
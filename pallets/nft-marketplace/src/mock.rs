@@ -0,0 +1,207 @@
+//! Mocks for the NFT marketplace module.
+
+#![cfg(test)]
+
+use frame_support::{
+	construct_runtime,
+	parameter_types,
+	traits::{ConstBool, ConstU128, ConstU32, ConstU64, Everything, Nothing},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestSignature, UintAuthorityId},
+	traits::{ConvertInto, IdentityLookup},
+	BuildStorage, Permill,
+};
+
+use super::*;
+
+use crate as nft_marketplace;
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+pub type Balance = u128;
+pub type AssetId = u32;
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const FtPalletId: PalletId = PalletId(*b"ft/wrapn");
+	pub const MaxRoyalty: Permill = Permill::from_percent(10);
+}
+
+impl pallet_ft::Config for Runtime {
+	type Event = Event;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MintOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type StringLimit = ConstU32<8>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type BlockNumberToBalance = ConvertInto;
+	type MaxVestingSchedules = ConstU32<2>;
+	type MinVestedTransfer = ConstU128<10>;
+	type OnTransfer = ();
+	type TransferFilter = ();
+	type FeeExempt = Nothing;
+	type Currency = NativeBalances;
+	type NativeAssetId = ConstU32<WRAPPED_ASSET_ID>;
+	type PalletId = FtPalletId;
+	type MinimumBalance = ConstU128<1>;
+	type MaxSnapshotCheckpoints = ConstU32<2>;
+	type MaxBatchTransfers = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+impl pallet_nft::Config for Runtime {
+	type Event = Event;
+	type ClassId = u32;
+	type TokenId = u32;
+	type ClassData = ();
+	type TokenData = ();
+	type MaxClassMetadata = ConstU32<1>;
+	type MaxTokenMetadata = ConstU32<1>;
+	type TokenOwnerCanUpdateMetadata = ConstBool<false>;
+	type MaxRoyalty = MaxRoyalty;
+}
+
+parameter_types! {
+	pub const MarketplacePalletId: PalletId = PalletId(*b"nft-mkpl");
+	pub const RentReserveId: [u8; 8] = *b"mkplrent";
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type MaxBidDuration = ConstU64<1_000>;
+	type MinBidDuration = ConstU64<0>;
+	type MinBasePrice = ConstU128<1>;
+	type MaxBasePrice = ConstU128<1_000>;
+	type MinRentPrice = ConstU128<1>;
+	type MaxRentPrice = ConstU128<1_000>;
+	type MinCollateral = ConstU128<1>;
+	type MaxCollateral = ConstU128<1_000>;
+	type MinRentDuration = ConstU64<0>;
+	type MaxRentDuration = ConstU64<1_000>;
+	type ClassId = u32;
+	type TokenId = u32;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type PalletId = MarketplacePalletId;
+	type NFT = NFT;
+	type FT = FT;
+	type RentReserveId = RentReserveId;
+	type UnixTime = Timestamp;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		NativeBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		FT: pallet_ft::{Pallet, Call, Storage, Config<T>, Event<T>},
+		NFT: pallet_nft::{Pallet, Call, Storage, Config<T>, Event<T>},
+		NFTMarketplace: nft_marketplace::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const ASSET_ID: AssetId = 0;
+pub const WRAPPED_ASSET_ID: AssetId = 1;
+
+/// Builds the test externalities. Asset `ASSET_ID` is created with no balances and class 0 is
+/// created, owned by `ALICE`, with tokens 0, 1 and 2 owned by `ALICE`.
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		GenesisConfig {
+			ft: FTConfig {
+				assets: vec![
+					(ALICE, b"Token".to_vec(), b"TKN".to_vec(), 12, vec![]),
+					(ALICE, b"Wrapped".to_vec(), b"WNAT".to_vec(), 12, vec![]),
+				]
+			},
+			nft: NFTConfig {
+				classes: vec![
+					(ALICE, vec![1], (), vec![
+						(ALICE, vec![1], ()),
+						(ALICE, vec![1], ()),
+						(ALICE, vec![1], ()),
+					]),
+				]
+			},
+			..Default::default()
+		}
+			.assimilate_storage(&mut t).unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_ok,
+	storage::{storage_prefix, unhashed},
+	traits::OnRuntimeUpgrade,
+	StorageHasher, Twox64Concat,
+};
+use mock::*;
+
+/// Writes a balance of the single token of the fungible token pallet before it had assets.
+fn put_legacy_balance(who: AccountId, amount: u64) {
+	let key = [&storage_prefix(b"FT", b"Balances")[..], &Twox64Concat::hash(&who.encode())].concat();
+	unhashed::put(&key, &amount);
+}
+
+/// Runs the runtime upgrade in the order of the runtime: the FT balances first, then the
+/// marketplace hooks.
+fn upgrade_from_legacy_layout() {
+	StorageVersion::new(0).put::<FT>();
+	StorageVersion::new(0).put::<NFTMarketplace>();
+	pallet_ft::migrations::v1::migrate::<Runtime>();
+	<NFTMarketplace as OnRuntimeUpgrade>::on_runtime_upgrade();
+}
+
+#[test]
+fn migration_v1_should_name_asset_of_listings() {
+	ExtBuilder::default().build().execute_with(|| {
+		unhashed::put(&SellItems::<Runtime>::hashed_key_for(0, 0), &(BOB, u64::MAX, CHARLIE, 10u64, 20u64));
+		unhashed::put(&RentItems::<Runtime>::hashed_key_for(0, 1), &(BOB, CHARLIE, 50u64, 5u64, 10u64, 30u64, true));
+		StorageVersion::new(0).put::<NFTMarketplace>();
+
+		migrations::v1::migrate::<Runtime>();
+
+		assert_eq!(NFTMarketplace::sell_item(0, 0), Some(SellItem {
+			seller: BOB,
+			asset_id: ASSET_ID,
+			current_price: u64::MAX as Balance,
+			current_winner: CHARLIE,
+			start_time: 10,
+			end_time: 20,
+		}));
+		assert_eq!(NFTMarketplace::rent_item(0, 1), Some(RentItem {
+			leaser: BOB,
+			renter: CHARLIE,
+			asset_id: ASSET_ID,
+			collateral: 50,
+			price: 5,
+			start_time: 10,
+			duration: 30,
+			is_renting: true,
+		}));
+		assert_eq!(StorageVersion::get::<NFTMarketplace>(), 1);
+	});
+}
+
+#[test]
+fn migration_v2_should_restore_rental_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pallet_account = NFTMarketplace::pallet_account_id();
+		put_legacy_balance(pallet_account, 50);
+		unhashed::put(&storage_prefix(b"FT", b"TotalSupply"), &50u64);
+		// A rented NFT was held by its renter.
+		assert_ok!(NFT::transfer(&ALICE, &BOB, 0, 1));
+		unhashed::put(&RentItems::<Runtime>::hashed_key_for(0, 1), &(ALICE, BOB, 50u64, 5u64, 10u64, 30u64, true));
+
+		upgrade_from_legacy_layout();
+
+		assert_eq!(FT::reserved_balance(&RentReserveId::get(), &BOB, ASSET_ID), 50);
+		assert_eq!(FT::balance(ASSET_ID, &pallet_account), 0);
+		assert_eq!(FT::total_supply(ASSET_ID), 50);
+		assert!(NFT::is_owner_of(&BOB, 0, 1));
+		assert_eq!(StorageVersion::get::<NFTMarketplace>(), 3);
+	});
+}
+
+#[test]
+fn migration_v3_should_return_listed_nfts_to_owners() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pallet_account = NFTMarketplace::pallet_account_id();
+		// Listed and offered NFTs were held by the pallet account, rented ones by their renter.
+		assert_ok!(NFT::transfer(&ALICE, &pallet_account, 0, 0));
+		assert_ok!(NFT::transfer(&ALICE, &BOB, 0, 1));
+		assert_ok!(NFT::transfer(&ALICE, &pallet_account, 0, 2));
+		unhashed::put(&SellItems::<Runtime>::hashed_key_for(0, 0), &(ALICE, 10u64, ALICE, 10u64, 20u64));
+		unhashed::put(&RentItems::<Runtime>::hashed_key_for(0, 1), &(ALICE, BOB, 0u64, 5u64, 10u64, 30u64, true));
+		unhashed::put(&RentItems::<Runtime>::hashed_key_for(0, 2), &(ALICE, ALICE, 50u64, 5u64, 0u64, 30u64, false));
+
+		upgrade_from_legacy_layout();
+
+		assert!(NFT::is_owner_of(&ALICE, 0, 0));
+		assert_eq!(NFT::locked_tokens(0, 0), Some(pallet_account));
+		assert!(NFT::is_owner_of(&BOB, 0, 1));
+		assert!(!NFT::is_locked(0, 1));
+		assert!(NFT::is_owner_of(&ALICE, 0, 2));
+		assert_eq!(NFT::locked_tokens(0, 2), Some(pallet_account));
+		assert!(NFTMarketplace::sell_item(0, 0).is_some());
+		assert!(NFTMarketplace::rent_item(0, 2).is_some());
+		assert_eq!(StorageVersion::get::<NFTMarketplace>(), 3);
+	});
+}
@@ -2,14 +2,16 @@
 #![allow(clippy::unused_unit)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{ensure, pallet_prelude::*, traits::Get, BoundedVec, Parameter};
+use frame_support::{ensure, pallet_prelude::*, traits::Get, transactional, BoundedVec, Parameter};
 use scale_info::TypeInfo;
 use sp_runtime::{
-    traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, MaybeSerializeDeserialize, Member, One, Zero},
-    ArithmeticError, DispatchError, DispatchResult, RuntimeDebug,
+    traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Hash, MaybeSerializeDeserialize, Member, One, Zero},
+    ArithmeticError, DispatchError, DispatchResult, PerThing, Permill, RuntimeDebug,
 };
 use sp_std::vec::Vec;
-use pallet_traits::NFTForMarketplace;
+use pallet_traits::{NFTForMarketplace, NFTRoyalty};
+
+pub mod migrations;
 
 #[cfg(test)]
 pub mod mock;
@@ -17,6 +19,59 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Behaviour flags of a class.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ClassFlags(pub u8);
+
+impl ClassFlags {
+    /// Token owners can transfer their tokens
+    pub const TRANSFERABLE: Self = Self(0b0001);
+    /// Token owners can burn their tokens
+    pub const BURNABLE: Self = Self(0b0010);
+    /// The class owner can mint new tokens
+    pub const MINTABLE: Self = Self(0b0100);
+    /// The class owner can burn any token of the class
+    pub const CLASS_OWNER_CAN_BURN: Self = Self(0b1000);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn all() -> Self {
+        Self(Self::TRANSFERABLE.0 | Self::BURNABLE.0 | Self::MINTABLE.0 | Self::CLASS_OWNER_CAN_BURN.0)
+    }
+
+    /// Whether only defined flags are set.
+    pub fn is_valid(&self) -> bool {
+        self.0 & !Self::all().0 == 0
+    }
+
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl Default for ClassFlags {
+    /// Token owners can transfer and burn their tokens and the class owner can mint new ones.
+    /// The class owner cannot burn tokens it does not own.
+    fn default() -> Self {
+        Self(Self::TRANSFERABLE.0 | Self::BURNABLE.0 | Self::MINTABLE.0)
+    }
+}
+
+/// Royalty paid to `recipient` on every sale, as a share of the sale price.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Royalty<AccountId> {
+    /// Royalty recipient
+    pub recipient: AccountId,
+    /// Share of the sale price
+    pub rate: Permill,
+}
+
 /// Class info
 #[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub struct ClassInfo<TokenId, AccountId, Data, ClassMetadataOf> {
@@ -28,6 +83,10 @@ pub struct ClassInfo<TokenId, AccountId, Data, ClassMetadataOf> {
     pub owner: AccountId,
     /// Class Properties
     pub data: Data,
+    /// Class behaviour flags
+    pub flags: ClassFlags,
+    /// Royalty of every token in the class without its own
+    pub royalty: Option<Royalty<AccountId>>,
 }
 
 /// Token info
@@ -39,6 +98,8 @@ pub struct TokenInfo<AccountId, Data, TokenMetadataOf> {
     pub owner: AccountId,
     /// Token Properties
     pub data: Data,
+    /// Royalty overriding the one of the class
+    pub royalty: Option<Royalty<AccountId>>,
 }
 
 pub use pallet::*;
@@ -67,6 +128,12 @@ pub mod pallet {
         /// The maximum size of a token's metadata
         #[pallet::constant]
         type MaxTokenMetadata: Get<u32>;
+        /// Whether token owners, and not only the class owner, can update a token's metadata and data
+        #[pallet::constant]
+        type TokenOwnerCanUpdateMetadata: Get<bool>;
+        /// The maximum royalty rate of a class or token
+        #[pallet::constant]
+        type MaxRoyalty: Get<Permill>;
     }
 
     pub type ClassMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxClassMetadata>;
@@ -80,6 +147,8 @@ pub mod pallet {
     pub type TokenInfoOf<T> =
     TokenInfo<<T as frame_system::Config>::AccountId, <T as Config>::TokenData, TokenMetadataOf<T>>;
 
+    pub type RoyaltyOf<T> = Royalty<<T as frame_system::Config>::AccountId>;
+
     pub type GenesisTokenData<T> = (
         <T as frame_system::Config>::AccountId, // Token owner
         Vec<u8>,                                // Token metadata
@@ -110,7 +179,7 @@ pub mod pallet {
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
             self.classes.iter().for_each(|class| {
-                let class_id = Pallet::<T>::do_create_class(&class.0, class.1.to_vec(), class.2.clone())
+                let class_id = Pallet::<T>::do_create_class(&class.0, class.1.to_vec(), class.2.clone(), ClassFlags::default())
                     .expect("Create class cannot fail while building genesis");
                 for (account_id, token_metadata, token_data) in &class.3 {
                     Pallet::<T>::mint(&account_id, class_id, token_metadata.to_vec(), token_data.clone())
@@ -138,6 +207,24 @@ pub mod pallet {
         CannotDestroyClass,
         /// Failed because the Maximum amount of metadata was exceeded
         MaxMetadataExceeded,
+        /// Tokens of the class cannot be transferred
+        NonTransferable,
+        /// Tokens of the class cannot be burned
+        NonBurnable,
+        /// No more tokens can be minted in the class
+        NonMintable,
+        /// The token is locked
+        TokenIsLocked,
+        /// The token is not locked
+        TokenIsNotLocked,
+        /// The metadata is frozen and cannot be changed
+        MetadataFrozen,
+        /// The royalty rate is above `MaxRoyalty`
+        RoyaltyTooHigh,
+        /// The class flags contain undefined bits
+        InvalidFlags,
+        /// Tokens of the class are locked and must stay transferable
+        ClassHasLockedTokens,
     }
 
     #[pallet::event]
@@ -148,6 +235,26 @@ pub mod pallet {
         TokenMinted(T::ClassId, T::TokenId, T::AccountId),
         TokenBurned(T::ClassId, T::TokenId, T::AccountId),
         TokenTransfer(T::ClassId, T::TokenId, T::AccountId, T::AccountId),
+        /// A token delegate was set or cleared. [class_id, token_id, owner, delegate]
+        Approval(T::ClassId, T::TokenId, T::AccountId, Option<T::AccountId>),
+        /// An operator was allowed or disallowed to move all tokens of an owner. [owner, operator, approved]
+        ApprovalForAll(T::AccountId, T::AccountId, bool),
+        /// Flags of a class were permanently cleared. [class_id, remaining_flags]
+        ClassFlagsLocked(T::ClassId, ClassFlags),
+        /// A token was locked in place. [class_id, token_id, locker]
+        TokenLocked(T::ClassId, T::TokenId, T::AccountId),
+        /// A token was unlocked. [class_id, token_id, locker]
+        TokenUnlocked(T::ClassId, T::TokenId, T::AccountId),
+        /// The metadata of a class changed. [class_id, metadata_hash]
+        ClassMetadataSet(T::ClassId, T::Hash),
+        /// The metadata of a token changed. [class_id, token_id, metadata_hash]
+        TokenMetadataSet(T::ClassId, T::TokenId, T::Hash),
+        /// The data of a token changed. [class_id, token_id, data_hash]
+        TokenDataSet(T::ClassId, T::TokenId, T::Hash),
+        /// The metadata of a class, or of a single token, was permanently frozen. [class_id, token_id]
+        MetadataFrozen(T::ClassId, Option<T::TokenId>),
+        /// The royalty of a class, or of a single token, was set or cleared. [class_id, token_id, royalty]
+        RoyaltySet(T::ClassId, Option<T::TokenId>, Option<RoyaltyOf<T>>),
     }
 
     /// Next available class ID.
@@ -183,13 +290,46 @@ pub mod pallet {
         NMapKey<Twox64Concat, T::TokenId>
     ), (), ValueQuery>;
 
+    /// The account allowed to move a single token on behalf of its owner.
+    ///
+    /// Cleared whenever the token is transferred or burned.
+    #[pallet::storage]
+    #[pallet::getter(fn token_approval)]
+    pub type TokenApprovals<T: Config> = StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, T::AccountId>;
+
+    /// Operators allowed to move every token of an owner, keyed by owner and operator.
+    #[pallet::storage]
+    #[pallet::getter(fn is_approved_for_all)]
+    pub type OperatorApprovals<T: Config> = StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+    /// The account holding the lock of a token, for example a marketplace listing it.
+    #[pallet::storage]
+    #[pallet::getter(fn locked_tokens)]
+    pub type LockedTokens<T: Config> = StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, T::AccountId>;
+
+    /// Classes whose metadata, and the metadata and data of all their tokens, are frozen.
+    #[pallet::storage]
+    pub type FrozenClassMetadata<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, (), OptionQuery>;
+
+    /// Tokens whose metadata and data are frozen.
+    #[pallet::storage]
+    pub type FrozenTokenMetadata<T: Config> = StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::TokenId, (), OptionQuery>;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>().saturating_add(migrations::v2::migrate::<T>())
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -197,11 +337,12 @@ pub mod pallet {
         pub fn create_class(
             origin: OriginFor<T>,
             metadata: Vec<u8>,
-            data: T::ClassData
+            data: T::ClassData,
+            flags: ClassFlags
         ) -> DispatchResult{
             let creator = ensure_signed(origin)?;
 
-            Self::do_create_class(&creator, metadata, data)?;
+            Self::do_create_class(&creator, metadata, data, flags)?;
             Ok(())
         }
 
@@ -259,6 +400,120 @@ pub mod pallet {
             Self::do_destroy_class(&owner, class_id)?;
             Ok(())
         }
+
+        /// Set the account allowed to move a single token, or clear it with `None`.
+        ///
+        /// Callable by the token owner or one of its operators.
+        #[pallet::weight(0)]
+        pub fn approve(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            token_id: T::TokenId,
+            delegate: Option<T::AccountId>
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::do_approve(&who, class_id, token_id, delegate)?;
+            Ok(())
+        }
+
+        /// Allow or disallow `operator` to move every token of the caller.
+        #[pallet::weight(0)]
+        pub fn set_approval_for_all(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            approved: bool
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::do_set_approval_for_all(&owner, &operator, approved);
+            Ok(())
+        }
+
+        /// Move a token from `src` to `dst` as its owner, its delegate or an operator of `src`.
+        #[pallet::weight(0)]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            src: T::AccountId,
+            dst: T::AccountId,
+            class_id: T::ClassId,
+            token_id: T::TokenId
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_approved_or_owner(&who, class_id, token_id), Error::<T>::NoPermission);
+            Self::transfer(&src, &dst, class_id, token_id)?;
+            Ok(())
+        }
+
+        /// Permanently clear `flags` from a class. Cleared flags can never be set again.
+        #[pallet::weight(0)]
+        pub fn lock_flags(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            flags: ClassFlags
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::do_lock_flags(&owner, class_id, flags)?;
+            Ok(())
+        }
+
+        #[pallet::weight(0)]
+        pub fn set_class_metadata(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            metadata: Vec<u8>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::do_set_class_metadata(&owner, class_id, metadata)?;
+            Ok(())
+        }
+
+        #[pallet::weight(0)]
+        pub fn set_token_metadata(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            token_id: T::TokenId,
+            metadata: Vec<u8>
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::do_set_token_metadata(&who, class_id, token_id, metadata)?;
+            Ok(())
+        }
+
+        #[pallet::weight(0)]
+        pub fn set_token_data(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            token_id: T::TokenId,
+            data: T::TokenData
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::do_set_token_data(&who, class_id, token_id, data)?;
+            Ok(())
+        }
+
+        /// Permanently freeze the metadata of a class and all its tokens, or of a single token.
+        #[pallet::weight(0)]
+        pub fn freeze_metadata(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            token_id: Option<T::TokenId>
+        ) -> DispatchResult{
+            let who = ensure_signed(origin)?;
+            Self::do_freeze_metadata(&who, class_id, token_id)?;
+            Ok(())
+        }
+
+        /// Set or clear the royalty of a class, or override it for a single token.
+        #[pallet::weight(0)]
+        pub fn set_royalty(
+            origin: OriginFor<T>,
+            class_id: T::ClassId,
+            token_id: Option<T::TokenId>,
+            royalty: Option<RoyaltyOf<T>>
+        ) -> DispatchResult{
+            let owner = ensure_signed(origin)?;
+            Self::do_set_royalty(&owner, class_id, token_id, royalty)?;
+            Ok(())
+        }
     }
 }
 
@@ -267,7 +522,9 @@ impl<T: Config> Pallet<T> {
         creator: &T::AccountId,
         metadata: Vec<u8>,
         data: T::ClassData,
+        flags: ClassFlags,
     ) -> Result<T::ClassId, DispatchError> {
+        ensure!(flags.is_valid(), Error::<T>::InvalidFlags);
         let bounded_metadata: ClassMetadataOf<T> = metadata.try_into().map_err(|_err| Error::<T>::MaxMetadataExceeded)?;
         let class_id = NextClassId::<T>::try_mutate(|next_id| -> Result<T::ClassId, DispatchError>{
             let id = *next_id;
@@ -279,6 +536,8 @@ impl<T: Config> Pallet<T> {
             metadata: bounded_metadata,
             owner: creator.clone(),
             total_issuance: Default::default(),
+            flags,
+            royalty: None,
         };
         Classes::<T>::insert(class_id, new_class);
         Self::deposit_event(Event::<T>::ClassCreated(class_id, creator.clone()));
@@ -301,9 +560,11 @@ impl<T: Config> Pallet<T> {
                 metadata: bounded_metadata,
                 data: data,
                 owner: owner.clone(),
+                royalty: None,
             };
             Classes::<T>::try_mutate(class_id, |opt| -> DispatchResult{
                 let class = opt.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+                ensure!(class.flags.contains(ClassFlags::MINTABLE), Error::<T>::NonMintable);
                 class.total_issuance = class.total_issuance.checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
                 Ok(())
             })?;
@@ -325,36 +586,44 @@ impl<T: Config> Pallet<T> {
         Tokens::<T>::try_mutate(class_id, token_id, |opt| -> DispatchResult{
             let token = opt.as_mut().ok_or(Error::<T>::TokenNotFound)?;
             ensure!(token.owner == *src, Error::<T>::NoPermission);
+            ensure!(!LockedTokens::<T>::contains_key(class_id, token_id), Error::<T>::TokenIsLocked);
+            let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(class.flags.contains(ClassFlags::TRANSFERABLE), Error::<T>::NonTransferable);
             token.owner = dst.clone();
             TokenByOwner::<T>::insert((dst.clone(), class_id, token_id), ());
             TokenByOwner::<T>::remove((src.clone(), class_id, token_id));
+            TokenApprovals::<T>::remove(class_id, token_id);
             Ok(())
         })?;
         Self::deposit_event(Event::<T>::TokenTransfer(class_id, token_id, src.clone(), dst.clone()));
         Ok(())
     }
 
+    /// Burn a token as its owner, or as the class owner if the class allows it.
     pub fn burn(
-        owner: &T::AccountId,
+        who: &T::AccountId,
         class_id: T::ClassId,
         token_id: T::TokenId,
     ) -> DispatchResult {
-        if let Some(token) = Self::tokens(class_id, token_id) {
-            ensure!(
-                token.owner == *owner,
-                Error::<T>::NoPermission
-            );
-            Tokens::<T>::remove(class_id, token_id);
-            TokenByOwner::<T>::remove((owner.clone(), class_id, token_id));
-            Classes::<T>::try_mutate(class_id, |opt| -> DispatchResult{
-                let class = opt.as_mut().ok_or(Error::<T>::ClassNotFound)?;
-                class.total_issuance = class.total_issuance.checked_sub(&One::one()).ok_or(ArithmeticError::Underflow)?;
-                Ok(())
-            })?;
+        let token = Self::tokens(class_id, token_id).ok_or(Error::<T>::TokenNotFound)?;
+        let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+        ensure!(!LockedTokens::<T>::contains_key(class_id, token_id), Error::<T>::TokenIsLocked);
+        if token.owner == *who {
+            ensure!(class.flags.contains(ClassFlags::BURNABLE), Error::<T>::NonBurnable);
         } else {
-            return Err(Error::<T>::TokenNotFound.into());
+            ensure!(class.owner == *who, Error::<T>::NoPermission);
+            ensure!(class.flags.contains(ClassFlags::CLASS_OWNER_CAN_BURN), Error::<T>::NonBurnable);
         }
-        Self::deposit_event(Event::<T>::TokenBurned(class_id, token_id, owner.clone()));
+        Tokens::<T>::remove(class_id, token_id);
+        TokenByOwner::<T>::remove((token.owner.clone(), class_id, token_id));
+        TokenApprovals::<T>::remove(class_id, token_id);
+        FrozenTokenMetadata::<T>::remove(class_id, token_id);
+        Classes::<T>::try_mutate(class_id, |opt| -> DispatchResult{
+            let class = opt.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+            class.total_issuance = class.total_issuance.checked_sub(&One::one()).ok_or(ArithmeticError::Underflow)?;
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::TokenBurned(class_id, token_id, token.owner));
         Ok(())
     }
 
@@ -376,10 +645,221 @@ impl<T: Config> Pallet<T> {
         }
         Classes::<T>::remove(class_id);
         NextTokenId::<T>::remove(class_id);
+        FrozenClassMetadata::<T>::remove(class_id);
         Self::deposit_event(Event::<T>::ClassDestroyed(class_id, owner.clone()));
         Ok(())
     }
 
+    pub fn do_lock_flags(
+        owner: &T::AccountId,
+        class_id: T::ClassId,
+        flags: ClassFlags,
+    ) -> DispatchResult {
+        let remaining = Classes::<T>::try_mutate(class_id, |opt| -> Result<ClassFlags, DispatchError>{
+            let class = opt.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(class.owner == *owner, Error::<T>::NoPermission);
+            // Locked tokens must stay transferable to their locker.
+            ensure!(
+                !flags.contains(ClassFlags::TRANSFERABLE) || LockedTokens::<T>::iter_prefix(class_id).next().is_none(),
+                Error::<T>::ClassHasLockedTokens
+            );
+            class.flags.remove(flags);
+            Ok(class.flags)
+        })?;
+        Self::deposit_event(Event::<T>::ClassFlagsLocked(class_id, remaining));
+        Ok(())
+    }
+
+    pub fn lock(
+        owner: &T::AccountId,
+        locker: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> DispatchResult {
+        let token = Self::tokens(class_id, token_id).ok_or(Error::<T>::TokenNotFound)?;
+        ensure!(token.owner == *owner, Error::<T>::NoPermission);
+        ensure!(!LockedTokens::<T>::contains_key(class_id, token_id), Error::<T>::TokenIsLocked);
+        let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+        ensure!(class.flags.contains(ClassFlags::TRANSFERABLE), Error::<T>::NonTransferable);
+        LockedTokens::<T>::insert(class_id, token_id, locker);
+        Self::deposit_event(Event::<T>::TokenLocked(class_id, token_id, locker.clone()));
+        Ok(())
+    }
+
+    pub fn unlock(
+        locker: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> DispatchResult {
+        let current = Self::locked_tokens(class_id, token_id).ok_or(Error::<T>::TokenIsNotLocked)?;
+        ensure!(current == *locker, Error::<T>::NoPermission);
+        LockedTokens::<T>::remove(class_id, token_id);
+        Self::deposit_event(Event::<T>::TokenUnlocked(class_id, token_id, locker.clone()));
+        Ok(())
+    }
+
+    /// Move a locked token to `dst` on behalf of its locker. The lock is released.
+    #[transactional]
+    pub fn transfer_locked(
+        locker: &T::AccountId,
+        dst: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> DispatchResult {
+        let token = Self::tokens(class_id, token_id).ok_or(Error::<T>::TokenNotFound)?;
+        Self::unlock(locker, class_id, token_id)?;
+        Self::transfer(&token.owner, dst, class_id, token_id)
+    }
+
+    pub fn is_locked(
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> bool {
+        LockedTokens::<T>::contains_key(class_id, token_id)
+    }
+
+    pub fn do_set_class_metadata(
+        owner: &T::AccountId,
+        class_id: T::ClassId,
+        metadata: Vec<u8>,
+    ) -> DispatchResult {
+        let bounded_metadata: ClassMetadataOf<T> = metadata.try_into().map_err(|_err| Error::<T>::MaxMetadataExceeded)?;
+        ensure!(!Self::is_class_metadata_frozen(class_id), Error::<T>::MetadataFrozen);
+        let hash = T::Hashing::hash_of(&bounded_metadata);
+        Classes::<T>::try_mutate(class_id, |opt| -> DispatchResult{
+            let class = opt.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(class.owner == *owner, Error::<T>::NoPermission);
+            class.metadata = bounded_metadata;
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::ClassMetadataSet(class_id, hash));
+        Ok(())
+    }
+
+    pub fn do_set_token_metadata(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+        metadata: Vec<u8>,
+    ) -> DispatchResult {
+        let bounded_metadata: TokenMetadataOf<T> = metadata.try_into().map_err(|_err| Error::<T>::MaxMetadataExceeded)?;
+        Self::ensure_can_update_token(who, class_id, token_id)?;
+        let hash = T::Hashing::hash_of(&bounded_metadata);
+        Tokens::<T>::try_mutate(class_id, token_id, |opt| -> DispatchResult{
+            let token = opt.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+            token.metadata = bounded_metadata;
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::TokenMetadataSet(class_id, token_id, hash));
+        Ok(())
+    }
+
+    pub fn do_set_token_data(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+        data: T::TokenData,
+    ) -> DispatchResult {
+        Self::ensure_can_update_token(who, class_id, token_id)?;
+        let hash = T::Hashing::hash_of(&data);
+        Tokens::<T>::try_mutate(class_id, token_id, |opt| -> DispatchResult{
+            let token = opt.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+            token.data = data;
+            Ok(())
+        })?;
+        Self::deposit_event(Event::<T>::TokenDataSet(class_id, token_id, hash));
+        Ok(())
+    }
+
+    pub fn do_freeze_metadata(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: Option<T::TokenId>,
+    ) -> DispatchResult {
+        match token_id {
+            Some(token_id) => {
+                Self::ensure_can_update_token(who, class_id, token_id)?;
+                FrozenTokenMetadata::<T>::insert(class_id, token_id, ());
+            }
+            None => {
+                let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+                ensure!(class.owner == *who, Error::<T>::NoPermission);
+                ensure!(!Self::is_class_metadata_frozen(class_id), Error::<T>::MetadataFrozen);
+                FrozenClassMetadata::<T>::insert(class_id, ());
+            }
+        }
+        Self::deposit_event(Event::<T>::MetadataFrozen(class_id, token_id));
+        Ok(())
+    }
+
+    pub fn do_set_royalty(
+        owner: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: Option<T::TokenId>,
+        royalty: Option<RoyaltyOf<T>>,
+    ) -> DispatchResult {
+        let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+        ensure!(class.owner == *owner, Error::<T>::NoPermission);
+        if let Some(royalty) = &royalty {
+            ensure!(royalty.rate <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+        }
+        match token_id {
+            Some(token_id) => Tokens::<T>::try_mutate(class_id, token_id, |opt| -> DispatchResult{
+                let token = opt.as_mut().ok_or(Error::<T>::TokenNotFound)?;
+                token.royalty = royalty.clone();
+                Ok(())
+            })?,
+            None => Classes::<T>::mutate(class_id, |opt| {
+                if let Some(class) = opt {
+                    class.royalty = royalty.clone();
+                }
+            }),
+        }
+        Self::deposit_event(Event::<T>::RoyaltySet(class_id, token_id, royalty));
+        Ok(())
+    }
+
+    /// The royalty of a token, falling back to the royalty of its class.
+    pub fn royalty_of(
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> Option<RoyaltyOf<T>> {
+        let token = Self::tokens(class_id, token_id)?;
+        token.royalty.or_else(|| Self::classes(class_id).and_then(|class| class.royalty))
+    }
+
+    pub fn is_class_metadata_frozen(
+        class_id: T::ClassId,
+    ) -> bool {
+        FrozenClassMetadata::<T>::contains_key(class_id)
+    }
+
+    pub fn is_token_metadata_frozen(
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> bool {
+        FrozenTokenMetadata::<T>::contains_key(class_id, token_id)
+    }
+
+    /// Ensure `who` may change the metadata and data of a token that is not frozen.
+    fn ensure_can_update_token(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> DispatchResult {
+        let class = Self::classes(class_id).ok_or(Error::<T>::ClassNotFound)?;
+        let token = Self::tokens(class_id, token_id).ok_or(Error::<T>::TokenNotFound)?;
+        ensure!(
+            class.owner == *who || (T::TokenOwnerCanUpdateMetadata::get() && token.owner == *who),
+            Error::<T>::NoPermission
+        );
+        ensure!(
+            !Self::is_class_metadata_frozen(class_id) && !Self::is_token_metadata_frozen(class_id, token_id),
+            Error::<T>::MetadataFrozen
+        );
+        Ok(())
+    }
+
     pub fn is_owner_of(
         owner: &T::AccountId,
         class_id: T::ClassId,
@@ -387,6 +867,52 @@ impl<T: Config> Pallet<T> {
     ) -> bool {
         TokenByOwner::<T>::contains_key((owner.clone(), class_id, token_id))
     }
+
+    pub fn do_approve(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+        delegate: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let token = Self::tokens(class_id, token_id).ok_or(Error::<T>::TokenNotFound)?;
+        ensure!(
+            token.owner == *who || Self::is_approved_for_all(&token.owner, who),
+            Error::<T>::NoPermission
+        );
+        match &delegate {
+            Some(delegate) => TokenApprovals::<T>::insert(class_id, token_id, delegate),
+            None => TokenApprovals::<T>::remove(class_id, token_id),
+        }
+        Self::deposit_event(Event::<T>::Approval(class_id, token_id, token.owner, delegate));
+        Ok(())
+    }
+
+    pub fn do_set_approval_for_all(
+        owner: &T::AccountId,
+        operator: &T::AccountId,
+        approved: bool,
+    ) {
+        if approved {
+            OperatorApprovals::<T>::insert(owner, operator, true);
+        } else {
+            OperatorApprovals::<T>::remove(owner, operator);
+        }
+        Self::deposit_event(Event::<T>::ApprovalForAll(owner.clone(), operator.clone(), approved));
+    }
+
+    /// Whether `who` owns the token, is its delegate or is an operator of its owner.
+    pub fn is_approved_or_owner(
+        who: &T::AccountId,
+        class_id: T::ClassId,
+        token_id: T::TokenId,
+    ) -> bool {
+        match Self::tokens(class_id, token_id) {
+            Some(token) => token.owner == *who
+                || Self::token_approval(class_id, token_id).as_ref() == Some(who)
+                || Self::is_approved_for_all(&token.owner, who),
+            None => false,
+        }
+    }
 }
 
 impl<T: Config> NFTForMarketplace<T::AccountId, T::ClassId, T::TokenId> for Pallet<T>{
@@ -397,4 +923,26 @@ impl<T: Config> NFTForMarketplace<T::AccountId, T::ClassId, T::TokenId> for Pall
 	fn is_owner_of(owner: &T::AccountId, class_id: T::ClassId, token_id: T::TokenId) -> bool {
 		Self::is_owner_of(owner, class_id, token_id)
 	}
+
+	fn lock(owner: &T::AccountId, locker: &T::AccountId, class_id: T::ClassId, token_id: T::TokenId) -> DispatchResult {
+		Self::lock(owner, locker, class_id, token_id)
+	}
+
+	fn unlock(locker: &T::AccountId, class_id: T::ClassId, token_id: T::TokenId) -> DispatchResult {
+		Self::unlock(locker, class_id, token_id)
+	}
+
+	fn transfer_locked(locker: &T::AccountId, dst: &T::AccountId, class_id: T::ClassId, token_id: T::TokenId) -> DispatchResult {
+		Self::transfer_locked(locker, dst, class_id, token_id)
+	}
+
+	fn is_locked(class_id: T::ClassId, token_id: T::TokenId) -> bool {
+		Self::is_locked(class_id, token_id)
+	}
+}
+
+impl<T: Config, Balance: AtLeast32BitUnsigned + Copy> NFTRoyalty<T::AccountId, T::ClassId, T::TokenId, Balance> for Pallet<T>{
+	fn royalty_info(class_id: T::ClassId, token_id: T::TokenId, sale_price: Balance) -> Option<(T::AccountId, Balance)> {
+		Self::royalty_of(class_id, token_id).map(|royalty| (royalty.recipient, royalty.rate.mul_floor(sale_price)))
+	}
 }
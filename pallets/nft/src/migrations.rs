@@ -0,0 +1,90 @@
+//! Storage migrations of the NFT pallet.
+
+use super::*;
+
+/// Version 1 adds behaviour flags and a royalty to every class.
+pub mod v1 {
+    use super::*;
+    use frame_support::log;
+
+    /// Class info before version 1.
+    #[derive(Decode)]
+    pub struct OldClassInfo<TokenId, AccountId, Data, ClassMetadataOf> {
+        pub metadata: ClassMetadataOf,
+        pub total_issuance: TokenId,
+        pub owner: AccountId,
+        pub data: Data,
+    }
+
+    pub type OldClassInfoOf<T> = OldClassInfo<
+        <T as Config>::TokenId,
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::ClassData,
+        ClassMetadataOf<T>,
+    >;
+
+    /// Gives every existing class the default flags and no royalty, so that class owners keep the
+    /// permissions they had and cannot burn tokens they do not own.
+    pub fn migrate<T: Config>() -> Weight {
+        if StorageVersion::get::<Pallet<T>>() >= 1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: Weight = 0;
+        Classes::<T>::translate::<OldClassInfoOf<T>, _>(|_, old| {
+            translated += 1;
+            Some(ClassInfo {
+                metadata: old.metadata,
+                total_issuance: old.total_issuance,
+                owner: old.owner,
+                data: old.data,
+                flags: ClassFlags::default(),
+                royalty: None,
+            })
+        });
+        log::info!(target: "runtime::nft", "migrated {} classes to v1", translated);
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+    }
+}
+
+/// Version 2 adds a royalty to every token.
+pub mod v2 {
+    use super::*;
+    use frame_support::log;
+
+    /// Token info before version 2.
+    #[derive(Decode)]
+    pub struct OldTokenInfo<AccountId, Data, TokenMetadataOf> {
+        pub metadata: TokenMetadataOf,
+        pub owner: AccountId,
+        pub data: Data,
+    }
+
+    pub type OldTokenInfoOf<T> =
+    OldTokenInfo<<T as frame_system::Config>::AccountId, <T as Config>::TokenData, TokenMetadataOf<T>>;
+
+    /// Gives every existing token no royalty of its own, so that it falls back to the royalty of
+    /// its class.
+    pub fn migrate<T: Config>() -> Weight {
+        if StorageVersion::get::<Pallet<T>>() >= 2 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated: Weight = 0;
+        Tokens::<T>::translate::<OldTokenInfoOf<T>, _>(|_, _, old| {
+            translated += 1;
+            Some(TokenInfo {
+                metadata: old.metadata,
+                owner: old.owner,
+                data: old.data,
+                royalty: None,
+            })
+        });
+        log::info!(target: "runtime::nft", "migrated {} tokens to v2", translated);
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+    }
+}
@@ -48,6 +48,8 @@ impl frame_system::Config for Runtime {
 parameter_types! {
     pub const MaxClassMetadata: u32 = 1;
     pub const MaxTokenMetadata: u32 = 1;
+    pub static TokenOwnerCanUpdateMetadata: bool = false;
+    pub const MaxRoyalty: Permill = Permill::from_percent(10);
 }
 impl Config for Runtime {
     type Event = Event;
@@ -57,6 +59,8 @@ impl Config for Runtime {
     type TokenData = ();
     type MaxClassMetadata = MaxClassMetadata;
     type MaxTokenMetadata = MaxTokenMetadata;
+    type TokenOwnerCanUpdateMetadata = TokenOwnerCanUpdateMetadata;
+    type MaxRoyalty = MaxRoyalty;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -2,13 +2,14 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BlakeTwo256;
 use mock::*;
 
 #[test]
 fn create_class_should_work() {
     ExtBuilder::default().build().execute_with(|| {
-        assert_ok!(NFT::do_create_class(&ALICE, vec![1], ()));
-        assert_ok!(NFT::create_class(Origin::signed(BOB), vec![2], ()));
+        assert_ok!(NFT::do_create_class(&ALICE, vec![1], (), ClassFlags::all()));
+        assert_ok!(NFT::create_class(Origin::signed(BOB), vec![2], (), ClassFlags::all()));
     });
 }
 
@@ -17,7 +18,7 @@ fn create_class_should_fail() {
     ExtBuilder::default().build().execute_with(|| {
         NextClassId::<Runtime>::mutate(|id| *id = <Runtime as Config>::ClassId::max_value());
         assert_noop!(
-           NFT::do_create_class(&ALICE, vec![1], ()),
+           NFT::do_create_class(&ALICE, vec![1], (), ClassFlags::all()),
            Error::<Runtime>::NoAvailableClassId
        );
     });
@@ -218,4 +219,398 @@ fn should_destroy_class_fail(){
         assert_ok!(NFT::burn_token(Origin::signed(PETER), 0, 2));
         assert_noop!(NFT::destroy_class(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn approve_should_work() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (ALICE, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::approve(Origin::signed(ALICE), 0, 0, Some(BOB)));
+        assert_eq!(NFT::token_approval(0, 0), Some(BOB));
+        System::assert_last_event(mock::Event::NFT(crate::Event::Approval(0, 0, ALICE, Some(BOB))));
+
+        assert_ok!(NFT::approve(Origin::signed(ALICE), 0, 0, None));
+        assert_eq!(NFT::token_approval(0, 0), None);
+        System::assert_last_event(mock::Event::NFT(crate::Event::Approval(0, 0, ALICE, None)));
+
+        assert_noop!(NFT::approve(Origin::signed(BOB), 0, 0, Some(BOB)), Error::<Runtime>::NoPermission);
+        assert_noop!(NFT::approve(Origin::signed(ALICE), 0, TOKEN_ID_NOT_EXIST, Some(BOB)), Error::<Runtime>::TokenNotFound);
+    });
+}
+
+#[test]
+fn transfer_from_should_work_for_delegate() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (ALICE, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_noop!(
+            NFT::transfer_from(Origin::signed(BOB), ALICE, PETER, 0, 0),
+            Error::<Runtime>::NoPermission
+        );
+        assert_ok!(NFT::approve(Origin::signed(ALICE), 0, 0, Some(BOB)));
+        assert_ok!(NFT::transfer_from(Origin::signed(BOB), ALICE, PETER, 0, 0));
+        assert_eq!(NFT::is_owner_of(&PETER, 0, 0), true);
+        // The approval does not survive the transfer.
+        assert_eq!(NFT::token_approval(0, 0), None);
+        assert_noop!(
+            NFT::transfer_from(Origin::signed(BOB), PETER, ALICE, 0, 0),
+            Error::<Runtime>::NoPermission
+        );
+    });
+}
+
+#[test]
+fn transfer_from_should_work_for_operator() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (ALICE, vec![1], ()),
+                (ALICE, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+        assert!(NFT::is_approved_for_all(ALICE, BOB));
+        System::assert_last_event(mock::Event::NFT(crate::Event::ApprovalForAll(ALICE, BOB, true)));
+
+        // An operator can move the tokens and delegate them.
+        assert_ok!(NFT::transfer_from(Origin::signed(BOB), ALICE, PETER, 0, 0));
+        assert_ok!(NFT::approve(Origin::signed(BOB), 0, 1, Some(ONLY)));
+        assert_ok!(NFT::transfer_from(Origin::signed(ONLY), ALICE, ONLY, 0, 1));
+
+        assert_ok!(NFT::set_approval_for_all(Origin::signed(ALICE), BOB, false));
+        assert!(!NFT::is_approved_for_all(ALICE, BOB));
+        System::assert_last_event(mock::Event::NFT(crate::Event::ApprovalForAll(ALICE, BOB, false)));
+    });
+}
+
+#[test]
+fn transfer_from_should_fail_with_wrong_source() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (ALICE, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::approve(Origin::signed(ALICE), 0, 0, Some(BOB)));
+        assert_noop!(
+            NFT::transfer_from(Origin::signed(BOB), PETER, BOB, 0, 0),
+            Error::<Runtime>::NoPermission
+        );
+    });
+}
+
+#[test]
+fn class_flags_should_restrict_transfer_and_mint() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(NFT::create_class(Origin::signed(ALICE), vec![1], (), ClassFlags(ClassFlags::MINTABLE.0 | ClassFlags::BURNABLE.0)));
+        assert_ok!(NFT::mint_token(Origin::signed(ALICE), BOB, 0, vec![1], ()));
+        assert_noop!(NFT::transfer_token(Origin::signed(BOB), PETER, 0, 0), Error::<Runtime>::NonTransferable);
+
+        assert_ok!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::MINTABLE));
+        System::assert_last_event(mock::Event::NFT(crate::Event::ClassFlagsLocked(0, ClassFlags::BURNABLE)));
+        assert_noop!(NFT::mint_token(Origin::signed(ALICE), BOB, 0, vec![1], ()), Error::<Runtime>::NonMintable);
+        assert_ok!(NFT::burn_token(Origin::signed(BOB), 0, 0));
+    });
+}
+
+#[test]
+fn class_flags_should_restrict_burn() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(NFT::create_class(Origin::signed(ALICE), vec![1], (), ClassFlags::all()));
+        assert_ok!(NFT::mint_token(Origin::signed(ALICE), BOB, 0, vec![1], ()));
+        assert_ok!(NFT::mint_token(Origin::signed(ALICE), PETER, 0, vec![1], ()));
+
+        // The class owner can burn tokens it does not own while the flag is set.
+        assert_ok!(NFT::burn_token(Origin::signed(ALICE), 0, 0));
+        assert_eq!(NFT::is_owner_of(&BOB, 0, 0), false);
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenBurned(0, 0, BOB)));
+
+        assert_ok!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::CLASS_OWNER_CAN_BURN));
+        assert_noop!(NFT::burn_token(Origin::signed(ALICE), 0, 1), Error::<Runtime>::NonBurnable);
+        assert_ok!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::BURNABLE));
+        assert_noop!(NFT::burn_token(Origin::signed(PETER), 0, 1), Error::<Runtime>::NonBurnable);
+    });
+}
+
+#[test]
+fn default_flags_should_not_let_class_owner_burn() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_eq!(NFT::classes(0).unwrap().flags, ClassFlags::default());
+        assert_noop!(NFT::burn_token(Origin::signed(ALICE), 0, 0), Error::<Runtime>::NonBurnable);
+        assert_ok!(NFT::burn_token(Origin::signed(BOB), 0, 0));
+    });
+}
+
+#[test]
+fn create_class_should_reject_undefined_flags() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(NFT::create_class(Origin::signed(ALICE), vec![1], (), ClassFlags(0b1_0000)), Error::<Runtime>::InvalidFlags);
+    });
+}
+
+#[test]
+fn migration_v1_should_add_default_flags() {
+    ExtBuilder::default().build().execute_with(|| {
+        let old = (BoundedVec::<u8, MaxClassMetadata>::try_from(vec![1]).unwrap(), 2u64, ALICE, ());
+        frame_support::storage::unhashed::put_raw(&Classes::<Runtime>::hashed_key_for(0), &old.encode());
+        StorageVersion::new(0).put::<NFT>();
+
+        migrations::v1::migrate::<Runtime>();
+
+        let class = NFT::classes(0).unwrap();
+        assert_eq!(class.owner, ALICE);
+        assert_eq!(class.total_issuance, 2);
+        assert_eq!(class.flags, ClassFlags::default());
+        assert_eq!(class.royalty, None);
+        assert_eq!(StorageVersion::get::<NFT>(), 1);
+    });
+}
+
+#[test]
+fn migration_v2_should_add_token_royalty() {
+    ExtBuilder::default().build().execute_with(|| {
+        let old = (BoundedVec::<u8, MaxTokenMetadata>::try_from(vec![1]).unwrap(), BOB, ());
+        frame_support::storage::unhashed::put_raw(&Tokens::<Runtime>::hashed_key_for(0, 0), &old.encode());
+        StorageVersion::new(1).put::<NFT>();
+
+        migrations::v2::migrate::<Runtime>();
+
+        let token = NFT::tokens(0, 0).unwrap();
+        assert_eq!(token.owner, BOB);
+        assert_eq!(token.royalty, None);
+        assert_eq!(StorageVersion::get::<NFT>(), 2);
+    });
+}
+
+#[test]
+fn lock_flags_should_fail() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![]),
+        ]
+    ).execute_with(|| {
+        assert_noop!(NFT::lock_flags(Origin::signed(BOB), 0, ClassFlags::MINTABLE), Error::<Runtime>::NoPermission);
+        assert_noop!(NFT::lock_flags(Origin::signed(ALICE), CLASS_ID_NOT_EXIST, ClassFlags::MINTABLE), Error::<Runtime>::ClassNotFound);
+    });
+}
+
+#[test]
+fn locked_token_should_not_move() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_noop!(NFT::lock(&ALICE, &PETER, 0, 0), Error::<Runtime>::NoPermission);
+        assert_ok!(NFT::lock(&BOB, &PETER, 0, 0));
+        assert!(NFT::is_locked(0, 0));
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenLocked(0, 0, PETER)));
+        assert_noop!(NFT::lock(&BOB, &ONLY, 0, 0), Error::<Runtime>::TokenIsLocked);
+
+        // The owner keeps the token but can neither move nor burn it.
+        assert_eq!(NFT::is_owner_of(&BOB, 0, 0), true);
+        assert_noop!(NFT::transfer_token(Origin::signed(BOB), ONLY, 0, 0), Error::<Runtime>::TokenIsLocked);
+        assert_noop!(NFT::burn_token(Origin::signed(BOB), 0, 0), Error::<Runtime>::TokenIsLocked);
+        assert_noop!(NFT::burn_token(Origin::signed(ALICE), 0, 0), Error::<Runtime>::TokenIsLocked);
+
+        assert_noop!(NFT::unlock(&ONLY, 0, 0), Error::<Runtime>::NoPermission);
+        assert_ok!(NFT::unlock(&PETER, 0, 0));
+        assert!(!NFT::is_locked(0, 0));
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenUnlocked(0, 0, PETER)));
+        assert_noop!(NFT::unlock(&PETER, 0, 0), Error::<Runtime>::TokenIsNotLocked);
+        assert_ok!(NFT::transfer_token(Origin::signed(BOB), ONLY, 0, 0));
+    });
+}
+
+#[test]
+fn non_transferable_token_should_not_lock() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(NFT::create_class(Origin::signed(ALICE), vec![1], (), ClassFlags(ClassFlags::MINTABLE.0 | ClassFlags::BURNABLE.0)));
+        assert_ok!(NFT::mint_token(Origin::signed(ALICE), BOB, 0, vec![1], ()));
+        assert_noop!(NFT::lock(&BOB, &PETER, 0, 0), Error::<Runtime>::NonTransferable);
+    });
+}
+
+#[test]
+fn lock_flags_should_keep_locked_tokens_transferable() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::lock(&BOB, &PETER, 0, 0));
+        assert_noop!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::TRANSFERABLE), Error::<Runtime>::ClassHasLockedTokens);
+        assert_ok!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::MINTABLE));
+
+        assert_ok!(NFT::unlock(&PETER, 0, 0));
+        assert_ok!(NFT::lock_flags(Origin::signed(ALICE), 0, ClassFlags::TRANSFERABLE));
+    });
+}
+
+#[test]
+fn locker_should_move_locked_token() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::lock(&BOB, &PETER, 0, 0));
+        assert_noop!(NFT::transfer_locked(&ONLY, &ONLY, 0, 0), Error::<Runtime>::NoPermission);
+        assert_ok!(NFT::transfer_locked(&PETER, &ONLY, 0, 0));
+        assert_eq!(NFT::is_owner_of(&ONLY, 0, 0), true);
+        assert!(!NFT::is_locked(0, 0));
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenTransfer(0, 0, BOB, ONLY)));
+    });
+}
+
+#[test]
+fn set_metadata_should_work() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::set_class_metadata(Origin::signed(ALICE), 0, vec![2]));
+        assert_eq!(NFT::classes(0).unwrap().metadata.into_inner(), vec![2]);
+        System::assert_last_event(mock::Event::NFT(crate::Event::ClassMetadataSet(0, BlakeTwo256::hash_of(&vec![2u8]))));
+
+        assert_ok!(NFT::set_token_metadata(Origin::signed(ALICE), 0, 0, vec![3]));
+        assert_eq!(NFT::tokens(0, 0).unwrap().metadata.into_inner(), vec![3]);
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenMetadataSet(0, 0, BlakeTwo256::hash_of(&vec![3u8]))));
+
+        assert_ok!(NFT::set_token_data(Origin::signed(ALICE), 0, 0, ()));
+        System::assert_last_event(mock::Event::NFT(crate::Event::TokenDataSet(0, 0, BlakeTwo256::hash_of(&()))));
+
+        assert_noop!(NFT::set_class_metadata(Origin::signed(BOB), 0, vec![2]), Error::<Runtime>::NoPermission);
+        assert_noop!(NFT::set_class_metadata(Origin::signed(ALICE), 0, vec![2, 2]), Error::<Runtime>::MaxMetadataExceeded);
+        assert_noop!(NFT::set_token_metadata(Origin::signed(BOB), 0, 0, vec![3]), Error::<Runtime>::NoPermission);
+
+        TokenOwnerCanUpdateMetadata::set(true);
+        assert_ok!(NFT::set_token_metadata(Origin::signed(BOB), 0, 0, vec![4]));
+        assert_noop!(NFT::set_token_metadata(Origin::signed(PETER), 0, 0, vec![4]), Error::<Runtime>::NoPermission);
+    });
+}
+
+#[test]
+fn frozen_metadata_should_not_change() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::freeze_metadata(Origin::signed(ALICE), 0, Some(0)));
+        System::assert_last_event(mock::Event::NFT(crate::Event::MetadataFrozen(0, Some(0))));
+        assert_noop!(NFT::set_token_metadata(Origin::signed(ALICE), 0, 0, vec![2]), Error::<Runtime>::MetadataFrozen);
+        assert_noop!(NFT::set_token_data(Origin::signed(ALICE), 0, 0, ()), Error::<Runtime>::MetadataFrozen);
+        assert_ok!(NFT::set_token_metadata(Origin::signed(ALICE), 0, 1, vec![2]));
+
+        assert_noop!(NFT::freeze_metadata(Origin::signed(BOB), 0, None), Error::<Runtime>::NoPermission);
+        assert_ok!(NFT::freeze_metadata(Origin::signed(ALICE), 0, None));
+        assert_noop!(NFT::set_class_metadata(Origin::signed(ALICE), 0, vec![2]), Error::<Runtime>::MetadataFrozen);
+        assert_noop!(NFT::set_token_metadata(Origin::signed(ALICE), 0, 1, vec![3]), Error::<Runtime>::MetadataFrozen);
+        assert_noop!(NFT::freeze_metadata(Origin::signed(ALICE), 0, None), Error::<Runtime>::MetadataFrozen);
+    });
+}
+
+#[test]
+fn freezes_should_be_removed_with_token_and_class() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_ok!(NFT::freeze_metadata(Origin::signed(ALICE), 0, Some(0)));
+        assert_ok!(NFT::freeze_metadata(Origin::signed(ALICE), 0, None));
+        assert_ok!(NFT::burn_token(Origin::signed(BOB), 0, 0));
+        assert!(!NFT::is_token_metadata_frozen(0, 0));
+
+        assert_ok!(NFT::destroy_class(Origin::signed(ALICE), 0));
+        assert!(!NFT::is_class_metadata_frozen(0));
+    });
+}
+
+#[test]
+fn royalty_should_work() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, 0, 1_000), None);
+
+        let class_royalty = Royalty { recipient: ALICE, rate: Permill::from_percent(5) };
+        assert_ok!(NFT::set_royalty(Origin::signed(ALICE), 0, None, Some(class_royalty.clone())));
+        System::assert_last_event(mock::Event::NFT(crate::Event::RoyaltySet(0, None, Some(class_royalty))));
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, 0, 1_000), Some((ALICE, 50)));
+
+        let token_royalty = Royalty { recipient: PETER, rate: Permill::from_percent(10) };
+        assert_ok!(NFT::set_royalty(Origin::signed(ALICE), 0, Some(1), Some(token_royalty)));
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, 0, 1_000), Some((ALICE, 50)));
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, 1, 1_000), Some((PETER, 100)));
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, TOKEN_ID_NOT_EXIST, 1_000), None);
+
+        assert_ok!(NFT::set_royalty(Origin::signed(ALICE), 0, None, None));
+        assert_eq!(<NFT as NFTRoyalty<_, _, _, u128>>::royalty_info(0, 0, 1_000), None);
+    });
+}
+
+#[test]
+fn set_royalty_should_fail() {
+    ExtBuilder::default().build_with_genesis(
+        vec![
+            (ALICE, vec![1], (), vec![
+                (BOB, vec![1], ()),
+            ]),
+        ]
+    ).execute_with(|| {
+        assert_noop!(
+            NFT::set_royalty(Origin::signed(ALICE), 0, None, Some(Royalty { recipient: ALICE, rate: Permill::from_percent(11) })),
+            Error::<Runtime>::RoyaltyTooHigh
+        );
+        assert_noop!(
+            NFT::set_royalty(Origin::signed(BOB), 0, Some(0), Some(Royalty { recipient: BOB, rate: Permill::from_percent(5) })),
+            Error::<Runtime>::NoPermission
+        );
+        assert_noop!(
+            NFT::set_royalty(Origin::signed(ALICE), 0, Some(TOKEN_ID_NOT_EXIST), None),
+            Error::<Runtime>::TokenNotFound
+        );
+        assert_noop!(
+            NFT::set_royalty(Origin::signed(ALICE), CLASS_ID_NOT_EXIST, None, None),
+            Error::<Runtime>::ClassNotFound
+        );
+    });
+}
@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use sp_runtime::DispatchResult;
+use frame_support::traits::tokens::BalanceStatus;
+use sp_runtime::{DispatchError, DispatchResult};
 
 pub trait NFTForMarketplace<AccountId, ClassId, TokenId>{
 
@@ -16,12 +17,159 @@ pub trait NFTForMarketplace<AccountId, ClassId, TokenId>{
 		class_id: ClassId,
 		token_id: TokenId,
 	) -> bool;
+
+	/// Lock a token of `owner` in place on behalf of `locker`. A locked token cannot be
+	/// transferred or burned until `locker` unlocks it or moves it with `transfer_locked`.
+	fn lock(
+		owner: &AccountId,
+		locker: &AccountId,
+		class_id: ClassId,
+		token_id: TokenId,
+	) -> DispatchResult;
+
+	fn unlock(
+		locker: &AccountId,
+		class_id: ClassId,
+		token_id: TokenId,
+	) -> DispatchResult;
+
+	/// Move a token locked by `locker` to `dst`, releasing the lock.
+	fn transfer_locked(
+		locker: &AccountId,
+		dst: &AccountId,
+		class_id: ClassId,
+		token_id: TokenId,
+	) -> DispatchResult;
+
+	fn is_locked(
+		class_id: ClassId,
+		token_id: TokenId,
+	) -> bool;
+}
+
+/// Royalties owed to creators on secondary sales.
+pub trait NFTRoyalty<AccountId, ClassId, TokenId, Balance>{
+	/// The royalty recipient and amount owed on a sale of the token for `sale_price`, or `None`
+	/// if neither the token nor its class has a royalty.
+	fn royalty_info(
+		class_id: ClassId,
+		token_id: TokenId,
+		sale_price: Balance,
+	) -> Option<(AccountId, Balance)>;
 }
 
-pub trait FTTransfer<AccountId>{
+pub trait FTTransfer<AccountId, AssetId, Balance>{
 	fn transfer(
 		src: &AccountId,
 		dst: &AccountId,
-		amount: u64
+		asset_id: AssetId,
+		amount: Balance
+	) -> DispatchResult;
+}
+
+/// Named reserves and freezes on top of `FTTransfer`, so that callers can hold tokens in place
+/// instead of moving them into an escrow account.
+pub trait FTReserve<AccountId, AssetId, Balance>: FTTransfer<AccountId, AssetId, Balance>{
+	/// The identifier of a named reserve or freeze.
+	type ReserveIdentifier;
+
+	fn reserved_balance(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		asset_id: AssetId,
+	) -> Balance;
+
+	fn reserve(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		asset_id: AssetId,
+		amount: Balance
+	) -> DispatchResult;
+
+	fn unreserve(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		asset_id: AssetId,
+		amount: Balance
 	) -> DispatchResult;
+
+	fn repatriate_reserved(
+		id: &Self::ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		asset_id: AssetId,
+		amount: Balance,
+		status: BalanceStatus
+	) -> DispatchResult;
+
+	fn frozen_balance(
+		who: &AccountId,
+		asset_id: AssetId,
+	) -> Balance;
+
+	fn set_freeze(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		asset_id: AssetId,
+		amount: Balance
+	) -> DispatchResult;
+
+	fn thaw(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		asset_id: AssetId,
+	) -> DispatchResult;
+}
+
+/// Historical balances, e.g. to weight votes by holdings at a past block.
+pub trait FTSnapshot<AccountId, AssetId, Balance>{
+	/// The identifier of a snapshot.
+	type SnapshotId;
+
+	fn snapshot(
+		asset_id: AssetId,
+	) -> Result<Self::SnapshotId, DispatchError>;
+
+	fn balance_at(
+		who: &AccountId,
+		asset_id: AssetId,
+		snapshot_id: Self::SnapshotId,
+	) -> Option<Balance>;
+
+	fn total_supply_at(
+		asset_id: AssetId,
+		snapshot_id: Self::SnapshotId,
+	) -> Option<Balance>;
+}
+
+/// Called after a fungible token balance moves. `from` is `None` for a mint and `to` is `None`
+/// for a burn.
+pub trait OnTransfer<AccountId, AssetId, Balance>{
+	fn on_transfer(
+		asset_id: AssetId,
+		from: Option<&AccountId>,
+		to: Option<&AccountId>,
+		amount: Balance,
+	);
+}
+
+impl<AccountId, AssetId, Balance> OnTransfer<AccountId, AssetId, Balance> for () {
+	fn on_transfer(_asset_id: AssetId, _from: Option<&AccountId>, _to: Option<&AccountId>, _amount: Balance) {}
+}
+
+/// Decides whether a fungible token balance may move. Returning an error vetoes the move and the
+/// error is returned to the caller. `from` is `None` for a mint and `to` is `None` for a burn.
+pub trait TransferFilter<AccountId, AssetId, Balance>{
+	fn filter(
+		asset_id: AssetId,
+		from: Option<&AccountId>,
+		to: Option<&AccountId>,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId, AssetId, Balance> TransferFilter<AccountId, AssetId, Balance> for () {
+	fn filter(_asset_id: AssetId, _from: Option<&AccountId>, _to: Option<&AccountId>, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
 }
@@ -14,7 +14,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto,
+		IdentifyAccount, NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature
 };
@@ -27,7 +30,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstU128, ConstU32, ConstU64, ConstU8, Contains, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -37,8 +41,10 @@ pub use frame_support::{
 	PalletId
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
+use pallet_ft::payment::FungibleTokenAdapter;
 use pallet_transaction_payment::CurrencyAdapter;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -57,6 +63,9 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of a fungible asset.
+pub type AssetId = u32;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -99,10 +108,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -123,6 +132,9 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+/// The minimum native balance an account must keep to exist.
+pub const EXISTENTIAL_DEPOSIT: Balance = 500;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -263,13 +275,13 @@ impl pallet_balances::Config for Runtime {
 	/// The ubiquitous event type.
 	type Event = Event;
 	type DustRemoval = ();
-	type ExistentialDeposit = ConstU128<500>;
+	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = FungibleTokenAdapter<Runtime, ConstU32<0>, CurrencyAdapter<Balances, ()>>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
@@ -281,15 +293,52 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	pub const FtStringLimit: u32 = 32;
+	pub const FtPalletId: PalletId = PalletId(*b"ft/wrapn");
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_ft::Config for Runtime {
 	type Event = Event;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MintOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type StringLimit = FtStringLimit;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<16>;
+	type MaxFreezes = ConstU32<16>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type BlockNumberToBalance = ConvertInto;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<1_000_000_000_000>;
+	type OnTransfer = ();
+	type TransferFilter = ();
+	type FeeExempt = FtFeeExempt;
+	type Currency = Balances;
+	type NativeAssetId = ConstU32<1>;
+	type PalletId = FtPalletId;
+	type MinimumBalance = ConstU128<1>;
+	type MaxSnapshotCheckpoints = ConstU32<64>;
+	type MaxBatchTransfers = ConstU32<256>;
+	type WeightInfo = pallet_ft::weights::SubstrateWeight<Runtime>;
+}
+
+/// Accounts that pay no FT transfer fee.
+pub struct FtFeeExempt;
+impl Contains<AccountId> for FtFeeExempt {
+	fn contains(who: &AccountId) -> bool {
+		*who == NFTMarketplacePalletId::get().into_account_truncating()
+	}
 }
 
 parameter_types! {
 	pub const MaxClassMetadata: u32 = 8;
 	pub const MaxTokenMetadata: u32 = 8;
-
+	pub const TokenOwnerCanUpdateMetadata: bool = false;
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
 }
 
 impl pallet_nft::Config for Runtime{
@@ -300,19 +349,22 @@ impl pallet_nft::Config for Runtime{
 	type TokenData = u32;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
+	type TokenOwnerCanUpdateMetadata = TokenOwnerCanUpdateMetadata;
+	type MaxRoyalty = MaxRoyalty;
 }
 parameter_types! {
 	pub const MaxBidDuration: u64 = 86400000;
 	pub const MinBidDuration: u64 = 0;
-	pub const MinBasePrice: u64 = 1000;
-	pub const MaxBasePrice: u64 = 1000000000;
-	pub const MinRentPrice: u64 = 1000;
-	pub const MaxRentPrice: u64 = 1000000000;
-	pub const MinCollateral: u64 = 1000;
-	pub const MaxCollateral: u64 = 1000000000;
+	pub const MinBasePrice: Balance = 1000;
+	pub const MaxBasePrice: Balance = 1000000000;
+	pub const MinRentPrice: Balance = 1000;
+	pub const MaxRentPrice: Balance = 1000000000;
+	pub const MinCollateral: Balance = 1000;
+	pub const MaxCollateral: Balance = 1000000000;
 	pub const MinRentDuration: u64 = 0;
 	pub const MaxRentDuration: u64 = 86400000;
  	pub const NFTMarketplacePalletId: PalletId = PalletId(*b"nft-mkpl");
+	pub const NFTMarketplaceRentReserveId: [u8; 8] = *b"mkplrent";
 }
 impl pallet_nft_marketplace::Config for Runtime {
 	type Event = Event;
@@ -328,9 +380,12 @@ impl pallet_nft_marketplace::Config for Runtime {
 	type MaxRentDuration = MaxRentDuration;
 	type ClassId = u32;
 	type TokenId = u32;
+	type AssetId = AssetId;
+	type Balance = Balance;
 	type PalletId = NFTMarketplacePalletId;
 	type NFT = NFT;
 	type FT = FT;
+	type RentReserveId = NFTMarketplaceRentReserveId;
 	type UnixTime = Timestamp;
 }
 
@@ -355,6 +410,9 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// Runtime migrations, run before the `on_runtime_upgrade` hooks of the pallets. The FT balances
+/// are migrated first because the marketplace migrations move tokens.
+pub type Migrations = (pallet_ft::migrations::v1::MigrateToV1<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -362,6 +420,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -510,6 +569,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ft_runtime_api::FtApi<Block, AccountId, AssetId, Balance> for Runtime {
+		fn balance_of(who: AccountId, asset_id: AssetId) -> Balance {
+			FT::balance(asset_id, who)
+		}
+
+		fn allowance(owner: AccountId, spender: AccountId, asset_id: AssetId) -> Balance {
+			FT::allowance_of(&owner, &spender, asset_id)
+		}
+
+		fn total_supply(asset_id: AssetId) -> Balance {
+			FT::total_supply(asset_id)
+		}
+
+		fn metadata(asset_id: AssetId) -> Option<(Vec<u8>, Vec<u8>, u8)> {
+			FT::metadata_of(asset_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (